@@ -1,17 +1,231 @@
-// Address and WIF helpers (placeholders)
-use crate::crypto::{PublicKey, PrivateKey};
-use crate::error::{Result, SdkError};
+// P2PKH addresses and WIF private keys
+use alloc::{string::String, vec::Vec};
+use crate::chain_params::ChainParams;
+use crate::crypto::{hash160, Hash160, PublicKey, PrivateKey};
+use crate::error::{Base58Error, Result, SdkError};
+use crate::script::{classify, templates::P2pkhTemplate, Script, ScriptType};
+use crate::util::{base58check_decode, base58check_encode};
+use core::fmt;
+use core::str::FromStr;
+use zeroize::Zeroize;
 
-#[derive(Debug, Clone, Copy)]
-pub enum Network { Mainnet, Testnet }
-impl Default for Network { fn default() -> Self { Network::Mainnet } }
+/// Which chain keys and addresses belong to. `Custom` carries caller-defined parameters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Network { #[default] Mainnet, Testnet, Regtest, Stn, Custom(ChainParams) }
 
-#[derive(Debug, Default, Clone)]
+impl Network {
+    pub fn params(self) -> ChainParams {
+        match self {
+            Network::Mainnet => ChainParams::MAINNET,
+            Network::Testnet => ChainParams::TESTNET,
+            Network::Regtest => ChainParams::REGTEST,
+            Network::Stn => ChainParams::STN,
+            Network::Custom(params) => params,
+        }
+    }
+
+    /// Base58Check version byte of P2PKH addresses
+    pub fn p2pkh_prefix(self) -> u8 { self.params().p2pkh_prefix }
+
+    /// Preset owning an address prefix. Testnet, regtest and STN share one, which maps to `Testnet`.
+    pub fn from_p2pkh_prefix(prefix: u8) -> Option<Self> {
+        match prefix {
+            0x00 => Some(Network::Mainnet),
+            0x6f => Some(Network::Testnet),
+            _ => None,
+        }
+    }
+
+    /// Base58Check version byte of WIF private keys
+    pub fn wif_prefix(self) -> u8 { self.params().wif_prefix }
+
+    pub fn from_wif_prefix(prefix: u8) -> Option<Self> {
+        match prefix {
+            0x80 => Some(Network::Mainnet),
+            0xef => Some(Network::Testnet),
+            _ => None,
+        }
+    }
+}
+
+impl From<ChainParams> for Network {
+    fn from(params: ChainParams) -> Self { Network::Custom(params) }
+}
+
+/// P2PKH address: `hash160` of a compressed public key plus its network
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Address { pub network: Network, pub payload: [u8; 20] }
 
-pub fn from_pubkey(_pk: &PublicKey, _net: Network) -> Address { Address { network: Network::Mainnet, payload: [0u8; 20] } }
-pub fn parse(_s: &str) -> Result<Address> { Err(SdkError::NotImplemented("address::parse")) }
-pub fn to_string(_a: &Address) -> String { String::new() }
+impl Address {
+    /// P2PKH locking script paying this address
+    pub fn to_locking_script(&self) -> Script { P2pkhTemplate::locking_script(self.payload) }
+
+    /// Recover the address a P2PKH locking script pays; scripts carry no network, so it is supplied
+    pub fn from_locking_script(script: &Script, network: Network) -> Result<Self> {
+        match classify(script) {
+            ScriptType::P2pkh { pubkey_hash } => Ok(Address { network, payload: pubkey_hash }),
+            _ => Err(SdkError::InvalidArgument("not a P2PKH locking script")),
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&base58check_encode(self.network.p2pkh_prefix(), &self.payload))
+    }
+}
+
+impl FromStr for Address {
+    type Err = SdkError;
+    fn from_str(s: &str) -> Result<Self> { parse(s) }
+}
+
+pub fn from_pubkey(pk: &PublicKey, net: Network) -> Address {
+    let Hash160(payload) = hash160(pk.as_bytes());
+    Address { network: net, payload }
+}
+
+/// Decode a Base58Check P2PKH address; checksum, prefix and length failures are [`SdkError::Base58`]
+pub fn parse(s: &str) -> Result<Address> {
+    let (version, payload) = base58check_decode(s.trim())?;
+    let network = Network::from_p2pkh_prefix(version).ok_or(Base58Error::UnknownVersion(version))?;
+    Ok(Address { network, payload: address_payload(&payload)? })
+}
+
+/// Like [`parse`], but the prefix must be `net`'s and the result is tagged with `net`
+pub fn parse_for(s: &str, net: Network) -> Result<Address> {
+    let (version, payload) = base58check_decode(s.trim())?;
+    if version != net.p2pkh_prefix() { return Err(Base58Error::UnknownVersion(version).into()); }
+    Ok(Address { network: net, payload: address_payload(&payload)? })
+}
+
+fn address_payload(payload: &[u8]) -> Result<[u8; 20]> {
+    Ok(payload.try_into().map_err(|_| Base58Error::BadLength(payload.len()))?)
+}
+
+pub fn to_string(a: &Address) -> String { base58check_encode(a.network.p2pkh_prefix(), &a.payload) }
+
+/// `version || key || 0x01?` in Base58Check; the suffix marks a key whose address uses the compressed pubkey
+pub fn wif_from_private_key(pk: &PrivateKey, net: Network, compressed: bool) -> String {
+    let mut payload = Vec::with_capacity(33);
+    payload.extend_from_slice(pk.as_bytes());
+    if compressed { payload.push(0x01); }
+    let wif = base58check_encode(net.wif_prefix(), &payload);
+    payload.zeroize();
+    wif
+}
+
+/// Decode a WIF into `(key, network, compressed)`; the key must be a valid non-zero scalar
+pub fn wif_to_private_key(wif: &str) -> Result<(PrivateKey, Network, bool)> {
+    decode_wif(wif, Network::from_wif_prefix)
+}
+
+/// Like [`wif_to_private_key`], but the prefix must be `net`'s
+pub fn wif_to_private_key_for(wif: &str, net: Network) -> Result<(PrivateKey, Network, bool)> {
+    decode_wif(wif, |version| (version == net.wif_prefix()).then_some(net))
+}
+
+fn decode_wif(wif: &str, network_of: impl Fn(u8) -> Option<Network>) -> Result<(PrivateKey, Network, bool)> {
+    let (version, mut payload) = base58check_decode(wif.trim())?;
+    let decoded = (|| {
+        let network = network_of(version).ok_or(Base58Error::UnknownVersion(version))?;
+        let compressed = match (payload.len(), payload.last()) {
+            (32, _) => false,
+            (33, Some(0x01)) => true,
+            (33, _) => return Err(SdkError::ParseError("invalid WIF compression flag")),
+            (n, _) => return Err(Base58Error::BadLength(n).into()),
+        };
+        Ok((PrivateKey::from_slice(&payload[..32])?, network, compressed))
+    })();
+    payload.zeroize();
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::hex_decode;
+
+    fn pubkey_of(d: u8) -> PublicKey {
+        let mut k = [0u8; 32];
+        k[31] = d;
+        PrivateKey::from_bytes(&k).unwrap().to_public_key()
+    }
+
+    #[test]
+    fn known_addresses() {
+        // compressed generator point (private key 1)
+        let a = from_pubkey(&pubkey_of(1), Network::Mainnet);
+        assert_eq!(to_string(&a), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+        assert_eq!(a.to_string(), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+        assert_eq!(a.payload.to_vec(), hex_decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap());
+        let t = from_pubkey(&pubkey_of(1), Network::Testnet);
+        assert_eq!(to_string(&t), "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r");
+    }
+
+    #[test]
+    fn parse_roundtrip_and_typed_errors() {
+        let a = from_pubkey(&pubkey_of(7), Network::Testnet);
+        assert_eq!(parse(&to_string(&a)).unwrap(), a);
+        assert_eq!("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH".parse::<Address>().unwrap().network, Network::Mainnet);
+        assert!(matches!(parse("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ"), Err(SdkError::Base58(Base58Error::BadChecksum))));
+        assert!(matches!(parse("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAM0"), Err(SdkError::Base58(Base58Error::InvalidCharacter))));
+        let p2sh = base58check_encode(0x05, &[0u8; 20]);
+        assert!(matches!(parse(&p2sh), Err(SdkError::Base58(Base58Error::UnknownVersion(0x05)))));
+        let short = base58check_encode(0x00, &[0u8; 19]);
+        assert!(matches!(parse(&short), Err(SdkError::Base58(Base58Error::BadLength(19)))));
+    }
+
+    #[test]
+    fn locking_script_roundtrip() {
+        let a = from_pubkey(&pubkey_of(1), Network::Mainnet);
+        let script = a.to_locking_script();
+        assert_eq!(crate::util::hex_encode(script.as_bytes()), "76a914751e76e8199196d454941c45d1b3a323f1433bd688ac");
+        assert_eq!(Address::from_locking_script(&script, Network::Mainnet).unwrap(), a);
+        assert!(Address::from_locking_script(&Script::new().push_opcode(crate::script::OP_RETURN), Network::Mainnet).is_err());
+    }
+
+    #[test]
+    fn wif_known_values() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let key = PrivateKey::from_bytes(&one).unwrap();
+        assert_eq!(wif_from_private_key(&key, Network::Mainnet, true), "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn");
+        assert_eq!(wif_from_private_key(&key, Network::Mainnet, false), "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf");
+        let (k, net, compressed) = wif_to_private_key("5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf").unwrap();
+        assert_eq!((k, net, compressed), (key.clone(), Network::Mainnet, false));
+        let testnet = wif_from_private_key(&key, Network::Testnet, true);
+        assert!(testnet.starts_with('c'));
+        assert_eq!(wif_to_private_key(&testnet).unwrap(), (key, Network::Testnet, true));
+    }
+
+    #[test]
+    fn custom_chain_params_flow_through() {
+        let params = ChainParams { name: "private", p2pkh_prefix: 0x1c, wif_prefix: 0x9c, ..ChainParams::REGTEST };
+        let net = Network::from(params);
+        let key = PrivateKey::from_bytes(&[0x33; 32]).unwrap();
+        let a = from_pubkey(&key.to_public_key(), net);
+        let s = to_string(&a);
+        assert_eq!(parse_for(&s, net).unwrap(), a);
+        assert!(matches!(parse(&s), Err(SdkError::Base58(Base58Error::UnknownVersion(0x1c)))));
+        assert!(parse_for(&s, Network::Mainnet).is_err());
+        let wif = wif_from_private_key(&key, net, true);
+        assert_eq!(wif_to_private_key_for(&wif, net).unwrap(), (key.clone(), net, true));
+        assert!(wif_to_private_key(&wif).is_err());
+        // regtest shares testnet's prefixes, so auto-detection reports Testnet
+        let regtest = to_string(&from_pubkey(&key.to_public_key(), Network::Regtest));
+        assert_eq!(parse(&regtest).unwrap().network, Network::Testnet);
+        assert_eq!(parse_for(&regtest, Network::Regtest).unwrap().network, Network::Regtest);
+    }
 
-pub fn wif_from_private_key(_pk: &PrivateKey, _net: Network, _compressed: bool) -> String { String::new() }
-pub fn wif_to_private_key(_wif: &str) -> Result<(PrivateKey, Network, bool)> { Err(SdkError::NotImplemented("wif_to_private_key")) }
+    #[test]
+    fn wif_rejections() {
+        assert!(matches!(wif_to_private_key(&base58check_encode(0x80, &[0u8; 32])), Err(SdkError::InvalidArgument(_))));
+        assert!(wif_to_private_key(&base58check_encode(0x80, &[0xff; 32])).is_err());
+        let mut bad_flag = [0x11u8; 33];
+        bad_flag[32] = 0x02;
+        assert!(matches!(wif_to_private_key(&base58check_encode(0x80, &bad_flag)), Err(SdkError::ParseError(_))));
+        assert!(matches!(wif_to_private_key(&base58check_encode(0x00, &[0x11; 32])), Err(SdkError::Base58(Base58Error::UnknownVersion(0)))));
+        assert!(matches!(wif_to_private_key(&base58check_encode(0x80, &[0x11; 31])), Err(SdkError::Base58(Base58Error::BadLength(31)))));
+    }
+}
@@ -0,0 +1,102 @@
+// Per-chain constants: encoding prefixes, genesis, ports and dust policy
+
+/// Everything that differs between BSV chains. Presets cover the public networks;
+/// private chains can fill in their own and pass them as `Network::Custom`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainParams {
+    pub name: &'static str,
+    /// Base58Check version byte of P2PKH addresses
+    pub p2pkh_prefix: u8,
+    /// Base58Check version byte of WIF private keys
+    pub wif_prefix: u8,
+    /// BIP32 version bytes of serialized extended private keys
+    pub xprv_version: [u8; 4],
+    /// BIP32 version bytes of serialized extended public keys
+    pub xpub_version: [u8; 4],
+    /// Genesis block hash in display (RPC) byte order
+    pub genesis_hash: [u8; 32],
+    pub default_port: u16,
+    pub rpc_port: u16,
+    /// Smallest output value, in satoshis, that nodes relay
+    pub dust_limit: u64,
+}
+
+impl ChainParams {
+    pub const MAINNET: ChainParams = ChainParams {
+        name: "main",
+        p2pkh_prefix: 0x00,
+        wif_prefix: 0x80,
+        xprv_version: [0x04, 0x88, 0xad, 0xe4],
+        xpub_version: [0x04, 0x88, 0xb2, 0x1e],
+        genesis_hash: hex32(b"000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"),
+        default_port: 8333,
+        rpc_port: 8332,
+        dust_limit: 1,
+    };
+
+    pub const TESTNET: ChainParams = ChainParams {
+        name: "test",
+        p2pkh_prefix: 0x6f,
+        wif_prefix: 0xef,
+        xprv_version: [0x04, 0x35, 0x83, 0x94],
+        xpub_version: [0x04, 0x35, 0x87, 0xcf],
+        genesis_hash: hex32(b"000000000933ea01ad0ee984209779baaec3ced90fa3f408719526f8d77f4943"),
+        default_port: 18333,
+        rpc_port: 18332,
+        dust_limit: 1,
+    };
+
+    pub const REGTEST: ChainParams = ChainParams {
+        name: "regtest",
+        genesis_hash: hex32(b"0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206"),
+        default_port: 18444,
+        ..Self::TESTNET
+    };
+
+    /// Scaling test network; shares testnet's genesis block and prefixes
+    pub const STN: ChainParams = ChainParams {
+        name: "stn",
+        default_port: 9333,
+        rpc_port: 9332,
+        ..Self::TESTNET
+    };
+
+    /// True when an output of `satoshis` would not be relayed
+    pub fn is_dust(&self, satoshis: u64) -> bool { satoshis < self.dust_limit }
+}
+
+impl Default for ChainParams {
+    fn default() -> Self { Self::MAINNET }
+}
+
+const fn hex32(s: &[u8; 64]) -> [u8; 32] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex in chain params"),
+        }
+    }
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = (nibble(s[2 * i]) << 4) | nibble(s[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::hex_encode;
+
+    #[test]
+    fn presets() {
+        assert_eq!(hex_encode(&ChainParams::MAINNET.genesis_hash), "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f");
+        assert_eq!(ChainParams::REGTEST.wif_prefix, 0xef);
+        assert_eq!(ChainParams::STN.genesis_hash, ChainParams::TESTNET.genesis_hash);
+        assert_eq!(ChainParams::default(), ChainParams::MAINNET);
+        assert!(ChainParams::MAINNET.is_dust(0) && !ChainParams::MAINNET.is_dust(1));
+    }
+}
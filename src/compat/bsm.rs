@@ -0,0 +1,114 @@
+// Bitcoin Signed Message (ts-sdk: compat/BSM)
+use alloc::{string::String, vec::Vec};
+use crate::crypto::{hash160, recover_public_key, sha256d, sign_hash_recoverable, verify_hash, Hash160, PrivateKey, PublicKey, Signature};
+use crate::error::{Result, SdkError};
+use crate::util::{base64_decode, base64_encode, write_varint};
+
+pub const MAGIC: &[u8] = b"Bitcoin Signed Message:\n";
+
+/// `sha256d(varint(len(MAGIC)) || MAGIC || varint(len(message)) || message)`
+pub fn magic_hash(message: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(MAGIC.len() + message.len() + 10);
+    write_varint(MAGIC.len() as u64, &mut buf);
+    buf.extend_from_slice(MAGIC);
+    write_varint(message.len() as u64, &mut buf);
+    buf.extend_from_slice(message);
+    sha256d(&buf)
+}
+
+/// 65-byte recoverable signature over the magic hash
+pub fn sign_compact(message: &[u8], key: &PrivateKey, compressed: bool) -> Result<[u8; 65]> {
+    let (sig, recovery_id) = sign_hash_recoverable(key, &magic_hash(message))?;
+    sig.to_compact_recoverable(recovery_id, compressed)
+}
+
+/// Base64 signature for a compressed key, the form wallets exchange
+pub fn sign(message: &[u8], key: &PrivateKey) -> Result<String> {
+    Ok(base64_encode(&sign_compact(message, key, true)?))
+}
+
+/// Recover the signing key from a base64 signature; also returns its compressed flag
+pub fn recover(message: &[u8], signature: &str) -> Result<(PublicKey, bool)> {
+    let raw = base64_decode(signature)?;
+    let (sig, recovery_id, compressed) = Signature::from_compact_recoverable(&raw)?;
+    let hash = magic_hash(message);
+    let key = recover_public_key(&hash, &sig, recovery_id)?;
+    // recovery alone does not reject every malformed (r, s), so check the signature too
+    if !verify_hash(&key, &hash, &sig)? { return Err(SdkError::CryptoError("signature does not verify")); }
+    Ok((key, compressed))
+}
+
+/// True when `signature` was made over `message` by `pubkey`
+pub fn verify(message: &[u8], signature: &str, pubkey: &PublicKey) -> Result<bool> {
+    Ok(recover(message, signature)?.0 == *pubkey)
+}
+
+/// True when `signature` was made by the key behind a P2PKH `address` (mainnet or testnet)
+pub fn verify_address(message: &[u8], signature: &str, address: &str) -> Result<bool> {
+    let address = crate::address::parse(address)?;
+    let (key, compressed) = recover(message, signature)?;
+    let Hash160(h) = hash160(&key.to_sec1_bytes(compressed)?);
+    Ok(h == address.payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::Sha256;
+    use crate::util::base58check_encode;
+
+    fn key() -> PrivateKey { PrivateKey::from_bytes(&[0x42; 32]).unwrap() }
+
+    fn address_of(pubkey: &PublicKey, compressed: bool, version: u8) -> String {
+        let Hash160(h) = hash160(&pubkey.to_sec1_bytes(compressed).unwrap());
+        base58check_encode(version, &h)
+    }
+
+    #[test]
+    fn magic_hash_framing() {
+        let mut expected = alloc::vec![0x18];
+        expected.extend_from_slice(b"Bitcoin Signed Message:\n");
+        expected.push(5);
+        expected.extend_from_slice(b"hello");
+        let Sha256(once) = crate::crypto::sha256(&expected);
+        assert_eq!(magic_hash(b"hello"), crate::crypto::sha256(&once).0);
+    }
+
+    #[test]
+    fn sign_verify_recover() {
+        let k = key();
+        let pubkey = k.to_public_key();
+        let sig = sign(b"login:1700000000", &k).unwrap();
+        assert_eq!(base64_decode(&sig).unwrap().len(), 65);
+        assert!(verify(b"login:1700000000", &sig, &pubkey).unwrap());
+        assert!(matches!(verify(b"login:1700000001", &sig, &pubkey), Ok(false)));
+        assert!(!verify(b"login:1700000000", &sig, &PrivateKey::from_bytes(&[7; 32]).unwrap().to_public_key()).unwrap());
+        assert_eq!(recover(b"login:1700000000", &sig).unwrap(), (pubkey, true));
+    }
+
+    #[test]
+    fn known_answer() {
+        // RFC 6979 nonce, low-S, compressed header (31 + recovery id); cross-checked against an independent implementation
+        const SIG: &str = "ID7QmvzEZwnbgQyE8R+pyPTi8Q0peSKqoyyoE02qlX2xQQVmd2ww8m9pFy1URit+hSLh6KNlnZ9MtdgGOme2HIk=";
+        let pubkey = key().to_public_key();
+        assert_eq!(crate::util::hex_encode(&pubkey.to_sec1_bytes(true).unwrap()), "0324653eac434488002cc06bbfb7f10fe18991e35f9fe4302dbea6d2353dc0ab1c");
+        assert_eq!(sign(b"hello world", &key()).unwrap(), SIG);
+        assert!(verify(b"hello world", SIG, &pubkey).unwrap());
+        assert!(matches!(verify(b"hello world!", SIG, &pubkey), Ok(false)));
+    }
+
+    #[test]
+    fn verify_against_addresses() {
+        let k = key();
+        let pubkey = k.to_public_key();
+        let sig = sign(b"msg", &k).unwrap();
+        assert!(verify_address(b"msg", &sig, &address_of(&pubkey, true, 0x00)).unwrap());
+        assert!(verify_address(b"msg", &sig, &address_of(&pubkey, true, 0x6f)).unwrap());
+        // the compressed flag selects which address form the signature vouches for
+        assert!(!verify_address(b"msg", &sig, &address_of(&pubkey, false, 0x00)).unwrap());
+        let legacy = base64_encode(&sign_compact(b"msg", &k, false).unwrap());
+        assert!(verify_address(b"msg", &legacy, &address_of(&pubkey, false, 0x00)).unwrap());
+        assert!(verify_address(b"msg", &sig, "not-an-address").is_err());
+        assert!(recover(b"msg", "AAAA").is_err());
+    }
+}
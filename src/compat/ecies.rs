@@ -0,0 +1,174 @@
+// ECIES in the Electrum (BIE1) and Bitcore layouts (ts-sdk: compat/ECIES)
+use alloc::vec::Vec;
+use crate::crypto::{hmac_sha256, sha512, PrivateKey, PublicKey, RandomSource, Sha512};
+use crate::error::{Result, SdkError};
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+pub const ELECTRUM_MAGIC: &[u8; 4] = b"BIE1";
+const MAC_LEN: usize = 32;
+const CBC_IV_LEN: usize = 16;
+
+fn sender_key<R: RandomSource + ?Sized>(from: Option<&PrivateKey>, rng: &mut R) -> Result<PrivateKey> {
+    match from {
+        Some(k) => Ok(k.clone()),
+        None => PrivateKey::random(rng),
+    }
+}
+
+fn check_mac(key: &[u8], data: &[u8], mac: &[u8]) -> Result<()> {
+    if bool::from(hmac_sha256(key, data).ct_eq(mac)) { Ok(()) } else { Err(SdkError::CryptoError("ecies: invalid checksum")) }
+}
+
+/// Electrum ECIES: `"BIE1" || ephemeral pubkey || AES-128-CBC(msg) || HMAC-SHA256`.
+/// Without `from` a fresh ephemeral key is drawn; `no_key` omits the sender key from the payload.
+pub fn electrum_encrypt<R: RandomSource + ?Sized>(message: &[u8], to: &PublicKey, from: Option<&PrivateKey>, no_key: bool, rng: &mut R) -> Result<Vec<u8>> {
+    let ephemeral = sender_key(from, rng)?;
+    let mut keys = electrum_keys(&ephemeral, to)?;
+    let mut out = Vec::with_capacity(4 + 33 + message.len() + CBC_IV_LEN + MAC_LEN);
+    out.extend_from_slice(ELECTRUM_MAGIC);
+    if !no_key { out.extend_from_slice(ephemeral.to_public_key().as_bytes()); }
+    out.extend_from_slice(&aes128_cbc_encrypt(&keys[16..32], &keys[..16], message));
+    let mac = hmac_sha256(&keys[32..], &out);
+    keys.zeroize();
+    out.extend_from_slice(&mac);
+    Ok(out)
+}
+
+/// Inverse of [`electrum_encrypt`]. Passing `from` means the payload was made with `no_key`.
+pub fn electrum_decrypt(encrypted: &[u8], to: &PrivateKey, from: Option<&PublicKey>) -> Result<Vec<u8>> {
+    let key_len = if from.is_some() { 0 } else { 33 };
+    if encrypted.len() < 4 + key_len + CBC_IV_LEN + MAC_LEN { return Err(SdkError::InvalidArgument("ecies: payload too short")); }
+    if &encrypted[..4] != ELECTRUM_MAGIC { return Err(SdkError::InvalidArgument("ecies: invalid magic")); }
+    let embedded;
+    let sender = match from {
+        Some(pk) => pk,
+        None => { embedded = PublicKey::from_sec1_bytes(&encrypted[4..37])?; &embedded }
+    };
+    let mut keys = electrum_keys(to, sender)?;
+    let (body, mac) = encrypted.split_at(encrypted.len() - MAC_LEN);
+    let plain = check_mac(&keys[32..], body, mac).and_then(|_| aes128_cbc_decrypt(&keys[16..32], &keys[..16], &body[4 + key_len..]));
+    keys.zeroize();
+    plain
+}
+
+// sha512 of the compressed ECDH point: iv || kE || kM
+fn electrum_keys(private: &PrivateKey, public: &PublicKey) -> Result<[u8; 64]> {
+    let shared = private.derive_shared_secret(public)?;
+    let Sha512(keys) = sha512(&shared.to_sec1(true));
+    Ok(keys)
+}
+
+/// Bitcore ECIES: `sender pubkey || iv || AES-256-CBC(msg) || HMAC-SHA256`.
+/// Without `from` a fresh ephemeral key is drawn; the IV always comes from `rng`.
+pub fn bitcore_encrypt<R: RandomSource + ?Sized>(message: &[u8], to: &PublicKey, from: Option<&PrivateKey>, rng: &mut R) -> Result<Vec<u8>> {
+    let ephemeral = sender_key(from, rng)?;
+    let mut iv = [0u8; CBC_IV_LEN];
+    rng.fill_bytes(&mut iv)?;
+    let mut keys = bitcore_keys(&ephemeral, to)?;
+    let mut out = Vec::with_capacity(33 + CBC_IV_LEN + message.len() + CBC_IV_LEN + MAC_LEN);
+    out.extend_from_slice(ephemeral.to_public_key().as_bytes());
+    out.extend_from_slice(&iv);
+    out.extend_from_slice(&aes256_cbc_encrypt(&keys[..32], &iv, message));
+    let mac = hmac_sha256(&keys[32..], &out[33..]);
+    keys.zeroize();
+    out.extend_from_slice(&mac);
+    Ok(out)
+}
+
+/// Inverse of [`bitcore_encrypt`]; accepts a compressed or uncompressed sender key
+pub fn bitcore_decrypt(encrypted: &[u8], to: &PrivateKey) -> Result<Vec<u8>> {
+    let key_len = if encrypted.first() == Some(&0x04) { 65 } else { 33 };
+    if encrypted.len() < key_len + 2 * CBC_IV_LEN + MAC_LEN { return Err(SdkError::InvalidArgument("ecies: payload too short")); }
+    let sender = PublicKey::from_sec1_bytes(&encrypted[..key_len])?;
+    let mut keys = bitcore_keys(to, &sender)?;
+    let (body, mac) = encrypted[key_len..].split_at(encrypted.len() - key_len - MAC_LEN);
+    let plain = check_mac(&keys[32..], body, mac).and_then(|_| aes256_cbc_decrypt(&keys[..32], &body[..CBC_IV_LEN], &body[CBC_IV_LEN..]));
+    keys.zeroize();
+    plain
+}
+
+// sha512 of the ECDH x coordinate: kE || kM
+fn bitcore_keys(private: &PrivateKey, public: &PublicKey) -> Result<[u8; 64]> {
+    let shared = private.derive_shared_secret(public)?;
+    let mut x = [0u8; 32];
+    x.copy_from_slice(&shared.to_sec1(true)[1..]);
+    let Sha512(keys) = sha512(&x);
+    x.zeroize();
+    Ok(keys)
+}
+
+fn aes128_cbc_encrypt(key: &[u8], iv: &[u8], pt: &[u8]) -> Vec<u8> {
+    // key and iv are fixed-size slices of the derived key material
+    cbc::Encryptor::<aes::Aes128>::new_from_slices(key, iv).unwrap().encrypt_padded_vec_mut::<Pkcs7>(pt)
+}
+
+fn aes128_cbc_decrypt(key: &[u8], iv: &[u8], ct: &[u8]) -> Result<Vec<u8>> {
+    cbc::Decryptor::<aes::Aes128>::new_from_slices(key, iv).unwrap()
+        .decrypt_padded_vec_mut::<Pkcs7>(ct).map_err(|_| SdkError::CryptoError("ecies: bad padding"))
+}
+
+fn aes256_cbc_encrypt(key: &[u8], iv: &[u8], pt: &[u8]) -> Vec<u8> {
+    cbc::Encryptor::<aes::Aes256>::new_from_slices(key, iv).unwrap().encrypt_padded_vec_mut::<Pkcs7>(pt)
+}
+
+fn aes256_cbc_decrypt(key: &[u8], iv: &[u8], ct: &[u8]) -> Result<Vec<u8>> {
+    cbc::Decryptor::<aes::Aes256>::new_from_slices(key, iv).unwrap()
+        .decrypt_padded_vec_mut::<Pkcs7>(ct).map_err(|_| SdkError::CryptoError("ecies: bad padding"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{base64_encode, hex_decode};
+
+    struct Counter(u8);
+    impl RandomSource for Counter {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()> {
+            for b in dest.iter_mut() { self.0 = self.0.wrapping_add(1); *b = self.0; }
+            Ok(())
+        }
+    }
+
+    fn key(hex: &str) -> PrivateKey { PrivateKey::from_slice(&hex_decode(hex).unwrap()).unwrap() }
+    fn alice() -> PrivateKey { key("77e06abc52bf065cb5164c5deca839d0276911991a2730be4d8d0a0307de7ceb") }
+    fn bob() -> PrivateKey { key("2b57c7c5e408ce927eef5e2efb49cfdadde77961d342daa72284bb3d6590862d") }
+    const MSG: &[u8] = b"this is my test message";
+
+    #[test]
+    fn electrum_known_value() {
+        let enc = electrum_encrypt(MSG, &bob().to_public_key(), Some(&alice()), false, &mut Counter(0)).unwrap();
+        assert_eq!(base64_encode(&enc), "QklFMQM55QTWSSsILaluEejwOXlrBs1IVcEB4kkqbxDz4Fap53XHOt6L3tKmrXho6yj6phfoiMkBOhUldRPnEI4fSZXbvZJHgyAzxA6SoujduvJXv+A9ri3po9veilrmc8p6dwo=");
+        assert_eq!(electrum_decrypt(&enc, &bob(), None).unwrap(), MSG);
+    }
+
+    #[test]
+    fn electrum_roundtrips() {
+        let (a, b) = (alice(), bob());
+        let enc = electrum_encrypt(MSG, &b.to_public_key(), None, false, &mut Counter(9)).unwrap();
+        assert_eq!(electrum_decrypt(&enc, &b, None).unwrap(), MSG);
+        let no_key = electrum_encrypt(MSG, &b.to_public_key(), Some(&a), true, &mut Counter(0)).unwrap();
+        assert_eq!(no_key.len(), enc.len() - 33);
+        assert_eq!(electrum_decrypt(&no_key, &b, Some(&a.to_public_key())).unwrap(), MSG);
+        let mut tampered = enc.clone();
+        tampered[40] ^= 1;
+        assert!(electrum_decrypt(&tampered, &b, None).is_err());
+        assert!(electrum_decrypt(&enc, &a, None).is_err());
+    }
+
+    #[test]
+    fn bitcore_roundtrips() {
+        let (a, b) = (alice(), bob());
+        let enc = bitcore_encrypt(MSG, &b.to_public_key(), Some(&a), &mut Counter(0)).unwrap();
+        assert_eq!(&enc[..33], a.to_public_key().as_bytes());
+        assert_eq!(base64_encode(&enc), "AznlBNZJKwgtqW4R6PA5eWsGzUhVwQHiSSpvEPPgVqnnAQIDBAUGBwgJCgsMDQ4PEAOGv8dRNbtlKfF9LFjGJkAfEZAc5UNiz/pDyVax0+UvXXndnavdg6N6ZBzcBZeq5XlCKiayPA5v5/mYSTjr1KM=");
+        assert_eq!(bitcore_decrypt(&enc, &b).unwrap(), MSG);
+        let ephemeral = bitcore_encrypt(b"", &b.to_public_key(), None, &mut Counter(50)).unwrap();
+        assert_eq!(bitcore_decrypt(&ephemeral, &b).unwrap(), b"");
+        let mut tampered = enc.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(bitcore_decrypt(&tampered, &b).is_err());
+        assert!(bitcore_decrypt(&enc[..60], &b).is_err());
+    }
+}
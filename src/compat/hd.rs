@@ -0,0 +1,362 @@
+// BIP32 hierarchical deterministic keys (ts-sdk: compat/HD)
+use crate::address::Network;
+use crate::crypto::{hash160, hmac_sha512, Hash160, PrivateKey, PublicKey};
+use crate::error::{Result, SdkError};
+use crate::util::{base58check_decode, base58check_encode};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use k256::elliptic_curve::ops::MulByGenerator;
+use k256::elliptic_curve::PrimeField;
+use k256::{ProjectivePoint, Scalar};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Child indices at or above this value are hardened
+pub const HARDENED: u32 = 0x8000_0000;
+
+fn version_bytes(network: Network, private: bool) -> [u8; 4] {
+    let params = network.params();
+    if private { params.xprv_version } else { params.xpub_version }
+}
+
+// Presets only; shared testnet/regtest/STN versions map to Testnet
+fn network_for_version(version: [u8; 4]) -> Result<(Network, bool)> {
+    [Network::Mainnet, Network::Testnet].into_iter()
+        .find_map(|n| network_matching(version, n))
+        .ok_or(SdkError::ParseError("unknown extended key version"))
+}
+
+fn network_matching(version: [u8; 4], network: Network) -> Option<(Network, bool)> {
+    if version == version_bytes(network, true) { return Some((network, true)); }
+    if version == version_bytes(network, false) { return Some((network, false)); }
+    None
+}
+
+/// Parse `m/44'/236'/0'/0/5` (also `h`/`H` for hardened) into child indices
+pub fn parse_path(path: &str) -> Result<Vec<u32>> {
+    let mut parts = path.trim().split('/');
+    if parts.next() != Some("m") { return Err(SdkError::ParseError("derivation path must start with m")); }
+    parts.map(|p| {
+        let (digits, hardened) = match p.strip_suffix(['\'', 'h', 'H']) {
+            Some(d) => (d, true),
+            None => (p, false),
+        };
+        let n: u32 = digits.parse().map_err(|_| SdkError::ParseError("invalid path component"))?;
+        if n >= HARDENED { return Err(SdkError::ParseError("path index out of range")); }
+        Ok(if hardened { n | HARDENED } else { n })
+    }).collect()
+}
+
+fn fingerprint_of(pubkey: &PublicKey) -> [u8; 4] {
+    let Hash160(h) = hash160(pubkey.as_bytes());
+    [h[0], h[1], h[2], h[3]]
+}
+
+// 78-byte BIP32 serialization: version, depth, parent fingerprint, child number, chain code, key data
+fn serialize(version: [u8; 4], depth: u8, parent: [u8; 4], child: u32, chain_code: &[u8; 32], key_data: &[u8; 33]) -> String {
+    let mut payload = Vec::with_capacity(77);
+    payload.extend_from_slice(&version[1..]);
+    payload.push(depth);
+    payload.extend_from_slice(&parent);
+    payload.extend_from_slice(&child.to_be_bytes());
+    payload.extend_from_slice(chain_code);
+    payload.extend_from_slice(key_data);
+    let s = base58check_encode(version[0], &payload);
+    payload.zeroize();
+    s
+}
+
+struct Decoded { version: [u8; 4], depth: u8, parent: [u8; 4], child: u32, chain_code: [u8; 32], key_data: [u8; 33] }
+
+impl Drop for Decoded {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
+        self.key_data.zeroize();
+    }
+}
+
+fn deserialize(s: &str) -> Result<Decoded> {
+    let (v0, mut payload) = base58check_decode(s)?;
+    if payload.len() != 77 { payload.zeroize(); return Err(SdkError::ParseError("extended key must be 78 bytes")); }
+    let mut d = Decoded {
+        version: [v0, payload[0], payload[1], payload[2]],
+        depth: payload[3],
+        parent: [payload[4], payload[5], payload[6], payload[7]],
+        child: u32::from_be_bytes([payload[8], payload[9], payload[10], payload[11]]),
+        chain_code: [0u8; 32],
+        key_data: [0u8; 33],
+    };
+    d.chain_code.copy_from_slice(&payload[12..44]);
+    d.key_data.copy_from_slice(&payload[44..77]);
+    payload.zeroize();
+    if d.depth == 0 && (d.parent != [0u8; 4] || d.child != 0) {
+        return Err(SdkError::ParseError("master key with non-zero parent or index"));
+    }
+    Ok(d)
+}
+
+#[derive(Clone)]
+pub struct ExtendedPrivateKey {
+    pub network: Network,
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+    chain_code: [u8; 32],
+    private_key: PrivateKey,
+}
+
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) { self.chain_code.zeroize() }
+}
+
+impl fmt::Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("network", &self.network)
+            .field("depth", &self.depth)
+            .field("child_number", &self.child_number)
+            .field("fingerprint", &self.fingerprint())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExtendedPrivateKey {
+    /// Master key from a BIP32/BIP39 seed (16..=64 bytes)
+    pub fn new_master(seed: &[u8], network: Network) -> Result<Self> {
+        if seed.len() < 16 || seed.len() > 64 { return Err(SdkError::InvalidArgument("seed must be 16 to 64 bytes")); }
+        let mut i = hmac_sha512(b"Bitcoin seed", seed);
+        let key = PrivateKey::from_slice(&i[..32]);
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&i[32..]);
+        i.zeroize();
+        Ok(Self { network, depth: 0, parent_fingerprint: [0u8; 4], child_number: 0, chain_code, private_key: key? })
+    }
+
+    pub fn private_key(&self) -> &PrivateKey { &self.private_key }
+    pub fn public_key(&self) -> PublicKey { self.private_key.to_public_key() }
+    pub fn chain_code(&self) -> &[u8; 32] { &self.chain_code }
+
+    /// First four bytes of hash160 of the public key
+    pub fn fingerprint(&self) -> [u8; 4] { fingerprint_of(&self.public_key()) }
+
+    /// CKDpriv; fails (with negligible probability) when the index yields an invalid key
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        let mut data = Zeroizing::new(Vec::with_capacity(37));
+        if index >= HARDENED {
+            data.push(0);
+            data.extend_from_slice(self.private_key.as_bytes());
+        } else {
+            data.extend_from_slice(self.public_key().as_bytes());
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+        // I = tweak || chain code; it and the child key bytes are wiped on every path out
+        let i = Zeroizing::new(hmac_sha512(&self.chain_code, &data));
+        let mut tweak = Option::<Scalar>::from(Scalar::from_repr(<[u8; 32]>::try_from(&i[..32]).unwrap().into()))
+            .ok_or(SdkError::CryptoError("derived tweak not below curve order; use the next index"))?;
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&i[32..]);
+        let mut sum = tweak + self.private_key.scalar();
+        let child = Zeroizing::new(<[u8; 32]>::from(sum.to_bytes()));
+        tweak.zeroize();
+        sum.zeroize();
+        Ok(Self {
+            network: self.network,
+            depth: self.depth.checked_add(1).ok_or(SdkError::InvalidArgument("maximum derivation depth reached"))?,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            private_key: PrivateKey::from_bytes(&child)?,
+        })
+    }
+
+    pub fn derive_path(&self, path: &str) -> Result<Self> {
+        parse_path(path)?.into_iter().try_fold(self.clone(), |k, i| k.derive_child(i))
+    }
+
+    /// Drop the private half ("neuter")
+    pub fn to_extended_public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            network: self.network,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            public_key: self.public_key(),
+        }
+    }
+
+    pub fn neuter(&self) -> ExtendedPublicKey { self.to_extended_public_key() }
+
+    /// `xprv...` / `tprv...`
+    pub fn to_base58(&self) -> String {
+        let mut key_data = [0u8; 33];
+        key_data[1..].copy_from_slice(self.private_key.as_bytes());
+        let s = serialize(version_bytes(self.network, true), self.depth, self.parent_fingerprint, self.child_number, &self.chain_code, &key_data);
+        key_data.zeroize();
+        s
+    }
+
+    pub fn from_base58(s: &str) -> Result<Self> {
+        let d = deserialize(s)?;
+        let version = network_for_version(d.version)?;
+        Self::from_decoded(&d, version)
+    }
+
+    /// Like [`ExtendedPrivateKey::from_base58`], but the version must be `network`'s
+    pub fn from_base58_for(s: &str, network: Network) -> Result<Self> {
+        let d = deserialize(s)?;
+        let version = network_matching(d.version, network).ok_or(SdkError::ParseError("extended key is for another network"))?;
+        Self::from_decoded(&d, version)
+    }
+
+    fn from_decoded(d: &Decoded, (network, private): (Network, bool)) -> Result<Self> {
+        if !private || d.key_data[0] != 0 { return Err(SdkError::ParseError("not an extended private key")); }
+        let private_key = PrivateKey::from_slice(&d.key_data[1..])?;
+        Ok(Self { network, depth: d.depth, parent_fingerprint: d.parent, child_number: d.child, chain_code: d.chain_code, private_key })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    pub network: Network,
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub public_key: PublicKey,
+}
+
+impl ExtendedPublicKey {
+    pub fn fingerprint(&self) -> [u8; 4] { fingerprint_of(&self.public_key) }
+
+    /// CKDpub; hardened indices need the private key and are rejected
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        if index >= HARDENED { return Err(SdkError::InvalidArgument("cannot derive hardened child from public key")); }
+        let mut data = Vec::with_capacity(37);
+        data.extend_from_slice(self.public_key.as_bytes());
+        data.extend_from_slice(&index.to_be_bytes());
+        let i = hmac_sha512(&self.chain_code, &data);
+        let tweak = Option::<Scalar>::from(Scalar::from_repr(<[u8; 32]>::try_from(&i[..32]).unwrap().into()))
+            .ok_or(SdkError::CryptoError("derived tweak not below curve order; use the next index"))?;
+        let point = ProjectivePoint::mul_by_generator(&tweak) + ProjectivePoint::from(self.public_key.to_affine()?);
+        if point == ProjectivePoint::IDENTITY { return Err(SdkError::CryptoError("derived point at infinity; use the next index")); }
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&i[32..]);
+        Ok(Self {
+            network: self.network,
+            depth: self.depth.checked_add(1).ok_or(SdkError::InvalidArgument("maximum derivation depth reached"))?,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            public_key: PublicKey::from_affine(&point.to_affine()),
+        })
+    }
+
+    pub fn derive_path(&self, path: &str) -> Result<Self> {
+        parse_path(path)?.into_iter().try_fold(self.clone(), |k, i| k.derive_child(i))
+    }
+
+    /// `xpub...` / `tpub...`
+    pub fn to_base58(&self) -> String {
+        serialize(version_bytes(self.network, false), self.depth, self.parent_fingerprint, self.child_number, &self.chain_code, self.public_key.as_bytes())
+    }
+
+    pub fn from_base58(s: &str) -> Result<Self> {
+        let d = deserialize(s)?;
+        let version = network_for_version(d.version)?;
+        Self::from_decoded(&d, version)
+    }
+
+    /// Like [`ExtendedPublicKey::from_base58`], but the version must be `network`'s
+    pub fn from_base58_for(s: &str, network: Network) -> Result<Self> {
+        let d = deserialize(s)?;
+        let version = network_matching(d.version, network).ok_or(SdkError::ParseError("extended key is for another network"))?;
+        Self::from_decoded(&d, version)
+    }
+
+    fn from_decoded(d: &Decoded, (network, private): (Network, bool)) -> Result<Self> {
+        if private { return Err(SdkError::ParseError("not an extended public key")); }
+        Ok(Self {
+            network,
+            depth: d.depth,
+            parent_fingerprint: d.parent,
+            child_number: d.child,
+            chain_code: d.chain_code,
+            public_key: PublicKey::from_sec1_bytes(&d.key_data)?,
+        })
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(&self.to_base58()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::hex_decode;
+
+    fn master() -> ExtendedPrivateKey {
+        // BIP32 test vector 1
+        ExtendedPrivateKey::new_master(&hex_decode("000102030405060708090a0b0c0d0e0f").unwrap(), Network::Mainnet).unwrap()
+    }
+
+    #[test]
+    fn bip32_vector_1() {
+        let m = master();
+        assert_eq!(m.to_base58(), "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi");
+        assert_eq!(m.neuter().to_base58(), "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8");
+        let c = m.derive_path("m/0'").unwrap();
+        assert_eq!(c.to_base58(), "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7");
+        let c = m.derive_path("m/0h/1").unwrap();
+        assert_eq!(c.neuter().to_base58(), "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ");
+        assert_eq!(c.parent_fingerprint, m.derive_path("m/0'").unwrap().fingerprint());
+        let leaf = m.derive_path("m/0'/1/2'/2/1000000000").unwrap();
+        assert_eq!(leaf.to_base58(), "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76");
+        assert_eq!(leaf.depth, 5);
+    }
+
+    #[test]
+    fn public_derivation_matches_private() {
+        let account = master().derive_path("m/44'/236'/0'").unwrap();
+        let via_xpub = account.neuter().derive_path("m/0/5").unwrap();
+        assert_eq!(via_xpub, account.derive_path("m/0/5").unwrap().neuter());
+        assert!(account.neuter().derive_child(HARDENED).is_err());
+    }
+
+    #[test]
+    fn base58_roundtrip_and_rejections() {
+        let k = master().derive_path("m/1/2'").unwrap();
+        let back = ExtendedPrivateKey::from_base58(&k.to_base58()).unwrap();
+        assert_eq!(back.to_base58(), k.to_base58());
+        let xpub = k.neuter().to_base58();
+        assert_eq!(ExtendedPublicKey::from_base58(&xpub).unwrap(), k.neuter());
+        assert!(ExtendedPrivateKey::from_base58(&xpub).is_err());
+        assert!(ExtendedPublicKey::from_base58(&k.to_base58()).is_err());
+        let t = ExtendedPrivateKey::new_master(&[7u8; 32], Network::Testnet).unwrap();
+        assert!(t.to_base58().starts_with("tprv"));
+        assert!(t.neuter().to_base58().starts_with("tpub"));
+        let custom = Network::Custom(crate::ChainParams { xprv_version: [1, 2, 3, 4], xpub_version: [1, 2, 3, 5], ..crate::ChainParams::REGTEST });
+        let c = ExtendedPrivateKey::new_master(&[7u8; 32], custom).unwrap();
+        assert_eq!(ExtendedPrivateKey::from_base58_for(&c.to_base58(), custom).unwrap().network, custom);
+        assert_eq!(ExtendedPublicKey::from_base58_for(&c.neuter().to_base58(), custom).unwrap(), c.neuter());
+        assert!(ExtendedPrivateKey::from_base58(&c.to_base58()).is_err());
+        assert!(ExtendedPrivateKey::from_base58_for(&t.to_base58(), Network::Mainnet).is_err());
+    }
+
+    #[test]
+    fn path_parsing() {
+        assert_eq!(parse_path("m/44'/236'/0'/0/5").unwrap(), vec![44 | HARDENED, 236 | HARDENED, HARDENED, 0, 5]);
+        assert_eq!(parse_path("m").unwrap(), Vec::<u32>::new());
+        assert!(parse_path("44/0").is_err());
+        assert!(parse_path("m/2147483648").is_err());
+        assert!(parse_path("m/x").is_err());
+    }
+
+    #[test]
+    fn debug_hides_secrets() {
+        let m = master();
+        let printed = format!("{:?}", m);
+        assert!(!printed.contains("chain_code") && !printed.contains("private_key"));
+    }
+}
@@ -0,0 +1,259 @@
+// BIP39 mnemonic phrases (ts-sdk: compat/Mnemonic)
+use alloc::{string::String, vec::Vec};
+use crate::crypto::{pbkdf2_hmac_sha512, sha256, RandomSource, Sha256};
+use crate::error::{Result, SdkError};
+use core::fmt;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::{Zeroize, Zeroizing};
+
+const ENGLISH: &str = include_str!("wordlists/english.txt");
+const PBKDF2_ROUNDS: u32 = 2048;
+
+/// 2048 words indexed by 11-bit value; words are stored NFKD-normalized
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wordlist {
+    words: Vec<String>,
+    separator: String,
+    // word indices in sorted word order, for lookups by binary search
+    sorted: Vec<u16>,
+}
+
+static ENGLISH_LIST: spin::Once<Wordlist> = spin::Once::new();
+
+impl Wordlist {
+    /// Build a list from exactly 2048 distinct words, joined with `separator` when rendering phrases
+    pub fn new<S: AsRef<str>>(words: &[S], separator: &str) -> Result<Self> {
+        if words.len() != 2048 { return Err(SdkError::InvalidArgument("wordlist must have 2048 words")); }
+        if separator.is_empty() { return Err(SdkError::InvalidArgument("wordlist separator must not be empty")); }
+        let words: Vec<String> = words.iter().map(|w| w.as_ref().trim().nfkd().collect()).collect();
+        if words.iter().any(String::is_empty) { return Err(SdkError::InvalidArgument("wordlist contains an empty word")); }
+        let list = Self::indexed(words, separator);
+        if list.sorted.windows(2).any(|w| list.words[w[0] as usize] == list.words[w[1] as usize]) {
+            return Err(SdkError::InvalidArgument("wordlist contains duplicate words"));
+        }
+        Ok(list)
+    }
+
+    fn indexed(words: Vec<String>, separator: &str) -> Self {
+        let mut sorted: Vec<u16> = (0..words.len() as u16).collect();
+        sorted.sort_unstable_by(|&a, &b| words[a as usize].cmp(&words[b as usize]));
+        Self { words, separator: separator.into(), sorted }
+    }
+
+    /// One word per line, as the lists in the BIP39 repository are published
+    pub fn from_text(text: &str, separator: &str) -> Result<Self> {
+        Self::new(&text.lines().filter(|l| !l.trim().is_empty()).collect::<Vec<_>>(), separator)
+    }
+
+    /// The bundled BIP39 English list, built on first use
+    pub fn english() -> &'static Self {
+        // the bundled list is ASCII (already NFKD) and checked by the tests, so it skips validation
+        ENGLISH_LIST.call_once(|| Self::indexed(ENGLISH.lines().map(String::from).collect(), " "))
+    }
+
+    pub fn word(&self, index: usize) -> Option<&str> { self.words.get(index).map(String::as_str) }
+    pub fn index_of(&self, word: &str) -> Option<usize> {
+        let find = |word: &str| self.sorted
+            .binary_search_by(|&i| self.words[i as usize].as_str().cmp(word))
+            .ok()
+            .map(|pos| self.sorted[pos] as usize);
+        if word.is_ascii() { find(word) } else { find(&word.nfkd().collect::<String>()) }
+    }
+    pub fn separator(&self) -> &str { &self.separator }
+}
+
+impl Default for Wordlist {
+    fn default() -> Self { Self::english().clone() }
+}
+
+/// A checked phrase together with the entropy it encodes
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic { entropy: Vec<u8>, phrase: String }
+
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.entropy.zeroize();
+        self.phrase.zeroize();
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mnemonic(<redacted> words={})", self.word_count())
+    }
+}
+
+impl Mnemonic {
+    /// Encode 16..=32 bytes (a multiple of 4) of entropy as 12..=24 English words
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self> { Self::from_entropy_in(entropy, Wordlist::english()) }
+
+    pub fn from_entropy_in(entropy: &[u8], wordlist: &Wordlist) -> Result<Self> {
+        if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+            return Err(SdkError::InvalidArgument("entropy must be 16, 20, 24, 28 or 32 bytes"));
+        }
+        let Sha256(check) = sha256(entropy);
+        let mut bits = entropy.to_vec();
+        bits.push(check[0]);
+        let word_count = (entropy.len() * 8 + entropy.len() / 4) / 11;
+        let mut phrase = String::new();
+        for i in 0..word_count {
+            if i > 0 { phrase.push_str(&wordlist.separator); }
+            phrase.push_str(&wordlist.words[read_11_bits(&bits, i * 11)]);
+        }
+        bits.zeroize();
+        Ok(Self { entropy: entropy.to_vec(), phrase })
+    }
+
+    /// Fresh phrase of 12, 15, 18, 21 or 24 English words
+    pub fn generate<R: RandomSource + ?Sized>(word_count: usize, rng: &mut R) -> Result<Self> {
+        Self::generate_in(word_count, rng, Wordlist::english())
+    }
+
+    pub fn generate_in<R: RandomSource + ?Sized>(word_count: usize, rng: &mut R, wordlist: &Wordlist) -> Result<Self> {
+        if !matches!(word_count, 12 | 15 | 18 | 21 | 24) { return Err(SdkError::InvalidArgument("word count must be 12, 15, 18, 21 or 24")); }
+        let mut entropy = [0u8; 32];
+        let len = word_count * 4 / 3;
+        rng.fill_bytes(&mut entropy[..len])?;
+        let m = Self::from_entropy_in(&entropy[..len], wordlist);
+        entropy.zeroize();
+        m
+    }
+
+    /// Parse an English phrase, validating every word and the checksum
+    pub fn from_phrase(phrase: &str) -> Result<Self> { Self::from_phrase_in(phrase, Wordlist::english()) }
+
+    /// Words are split on the list's separator; a whitespace separator accepts any run of whitespace
+    pub fn from_phrase_in(phrase: &str, wordlist: &Wordlist) -> Result<Self> {
+        let words: Vec<&str> = if wordlist.separator.trim().is_empty() {
+            phrase.split_whitespace().collect()
+        } else {
+            phrase.trim().split(wordlist.separator.as_str()).map(str::trim).collect()
+        };
+        // the word indices and everything derived from them are the seed, so all of it is wiped
+        let indices = Zeroizing::new(words.into_iter()
+            .map(|w| wordlist.index_of(w).ok_or(SdkError::ParseError("word not in wordlist")))
+            .collect::<Result<Vec<_>>>()?);
+        if !matches!(indices.len(), 12 | 15 | 18 | 21 | 24) { return Err(SdkError::ParseError("phrase must be 12, 15, 18, 21 or 24 words")); }
+        let bits = Zeroizing::new(vec_of_bits(&indices));
+        let ent_len = indices.len() * 4 / 3;
+        let Sha256(check) = sha256(&bits[..ent_len]);
+        let cs_bits = ent_len / 4;
+        let ok = (bits[ent_len] ^ check[0]) >> (8 - cs_bits) == 0;
+        let entropy = Zeroizing::new(bits[..ent_len].to_vec());
+        if !ok { return Err(SdkError::ParseError("invalid mnemonic checksum")); }
+        // re-render so the stored phrase is canonical (single separators, list spelling)
+        Self::from_entropy_in(&entropy, wordlist)
+    }
+
+    pub fn is_valid(phrase: &str) -> bool { Self::from_phrase(phrase).is_ok() }
+
+    pub fn phrase(&self) -> &str { &self.phrase }
+    pub fn entropy(&self) -> &[u8] { &self.entropy }
+    pub fn word_count(&self) -> usize { self.entropy.len() * 3 / 4 }
+
+    /// 64-byte BIP39 seed: PBKDF2-HMAC-SHA512, 2048 rounds, salt `"mnemonic" || passphrase` (both NFKD)
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        let mut password: String = self.phrase.nfkd().collect();
+        let mut salt: String = "mnemonic".chars().chain(passphrase.nfkd()).collect();
+        let mut seed = [0u8; 64];
+        pbkdf2_hmac_sha512(password.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        password.zeroize();
+        salt.zeroize();
+        seed
+    }
+}
+
+// Big-endian 11-bit group starting at bit `offset`
+fn read_11_bits(bytes: &[u8], offset: usize) -> usize {
+    (0..11).fold(0, |acc, i| {
+        let bit = offset + i;
+        (acc << 1) | ((bytes[bit / 8] >> (7 - bit % 8)) & 1) as usize
+    })
+}
+
+// Concatenate 11-bit indices, padding the final byte with zeros
+fn vec_of_bits(indices: &[usize]) -> Vec<u8> {
+    let mut out = alloc::vec![0u8; (indices.len() * 11).div_ceil(8)];
+    for (n, idx) in indices.iter().enumerate() {
+        for i in 0..11 {
+            if (idx >> (10 - i)) & 1 == 1 {
+                let bit = n * 11 + i;
+                out[bit / 8] |= 0x80 >> (bit % 8);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{hex_decode, hex_encode};
+
+    // Trezor reference vectors (passphrase "TREZOR")
+    const VECTORS: &[(&str, &str, &str)] = &[
+        ("00000000000000000000000000000000",
+         "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+         "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"),
+        ("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+         "legal winner thank year wave sausage worth useful legal winner thank yellow",
+         "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607"),
+        ("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+         "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+         "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad"),
+    ];
+
+    #[test]
+    fn reference_vectors() {
+        for (entropy, phrase, seed) in VECTORS {
+            let m = Mnemonic::from_entropy(&hex_decode(entropy).unwrap()).unwrap();
+            assert_eq!(m.phrase(), *phrase);
+            assert_eq!(hex_encode(&m.to_seed("TREZOR")), *seed);
+            assert_eq!(hex_encode(Mnemonic::from_phrase(phrase).unwrap().entropy()), *entropy);
+        }
+    }
+
+    #[test]
+    fn bundled_english_list() {
+        let english = Wordlist::english();
+        assert_eq!(*english, Wordlist::from_text(ENGLISH, " ").unwrap());
+        assert_eq!(english.index_of("abandon"), Some(0));
+        assert_eq!(english.index_of("zoo"), Some(2047));
+        assert_eq!(english.word(1000).and_then(|w| english.index_of(w)), Some(1000));
+        assert_eq!(english.index_of("notaword"), None);
+    }
+
+    #[test]
+    fn rejects_bad_phrases() {
+        let bad_checksum = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(Mnemonic::from_phrase(bad_checksum).is_err());
+        assert!(!Mnemonic::is_valid("abandon abandon about"));
+        assert!(!Mnemonic::is_valid("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon notaword"));
+        assert!(Mnemonic::from_entropy(&[0u8; 15]).is_err());
+        // extra whitespace is tolerated and canonicalised
+        let m = Mnemonic::from_phrase("  legal winner thank year wave sausage\tworth useful legal winner thank yellow ").unwrap();
+        assert_eq!(m.phrase(), VECTORS[1].1);
+    }
+
+    #[test]
+    fn custom_wordlist_and_generation() {
+        let words: Vec<String> = (0..2048).map(|i| alloc::format!("w{}", i)).collect();
+        let list = Wordlist::new(&words, "-").unwrap();
+        let m = Mnemonic::from_entropy_in(&[0u8; 16], &list).unwrap();
+        assert!(m.phrase().starts_with("w0-w0-"));
+        assert_eq!(Mnemonic::from_phrase_in(m.phrase(), &list).unwrap().entropy(), &[0u8; 16]);
+        assert!(Mnemonic::from_phrase_in(&m.phrase().replace('-', " "), &list).is_err());
+        assert!(Wordlist::new(&words[..2047], " ").is_err());
+        assert!(Wordlist::new(&words, "").is_err());
+
+        struct Fixed;
+        impl RandomSource for Fixed {
+            fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()> { dest.fill(0x7f); Ok(()) }
+        }
+        let g = Mnemonic::generate(12, &mut Fixed).unwrap();
+        assert_eq!(g.phrase(), VECTORS[1].1);
+        assert_eq!(Mnemonic::generate(24, &mut Fixed).unwrap().word_count(), 24);
+        assert!(Mnemonic::generate(13, &mut Fixed).is_err());
+        assert!(!alloc::format!("{:?}", g).contains("legal"));
+    }
+}
@@ -1,2 +1,10 @@
 // compat module (ts-sdk: compat)
-// Placeholder for BSM, ECIES, HD, Mnemonic, Utxo, etc.
+// Placeholder for Utxo, etc.
+
+pub mod bsm;
+pub mod ecies;
+pub mod hd;
+pub mod mnemonic;
+
+pub use hd::{ExtendedPrivateKey, ExtendedPublicKey, HARDENED};
+pub use mnemonic::{Mnemonic, Wordlist};
@@ -0,0 +1,113 @@
+// Bulk ECDSA verification for SPV/BEEF validation
+use super::ecdsa::scalar_from_bytes;
+use super::{PublicKey, Signature};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use k256::elliptic_curve::group::prime::PrimeCurveAffine;
+use k256::elliptic_curve::ops::{BatchInvert, LinearCombination, Reduce};
+use k256::elliptic_curve::point::{AffineCoordinates, BatchNormalize};
+use k256::{FieldBytes, ProjectivePoint, Scalar, U256};
+
+/// Items per unit of work; also the size of each batched inversion
+pub const BATCH_CHUNK: usize = 256;
+
+/// One `(key, digest, signature)` triple to check
+#[derive(Debug, Clone)]
+pub struct BatchItem<'a> {
+    pub pubkey: &'a PublicKey,
+    pub hash: [u8; 32],
+    pub sig: Signature,
+}
+
+/// Verify many signatures, returning one verdict per item in input order.
+///
+/// Each item costs one `u1*G + u2*Q` (Shamir's trick, via `lincomb`), but the
+/// per-item field and scalar inversions are amortised across a chunk with
+/// Montgomery's trick, and repeated public keys are decompressed once.
+/// With the `parallel` feature, chunks are spread across the rayon pool.
+/// Malformed keys or out-of-range scalars simply yield `false`.
+pub fn verify_batch(items: &[BatchItem<'_>]) -> Vec<bool> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items.par_chunks(BATCH_CHUNK).flat_map_iter(verify_chunk).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.chunks(BATCH_CHUNK).flat_map(verify_chunk).collect()
+    }
+}
+
+// (index into the chunk, Q, r, s, z) for every item that passed the cheap checks
+struct Prepared { index: usize, q: ProjectivePoint, r: Scalar, s: Scalar, z: Scalar }
+
+fn verify_chunk(chunk: &[BatchItem<'_>]) -> Vec<bool> {
+    let mut verdicts = alloc::vec![false; chunk.len()];
+    let mut keys: BTreeMap<[u8; 33], Option<ProjectivePoint>> = BTreeMap::new();
+    let mut prepared = Vec::with_capacity(chunk.len());
+    for (index, item) in chunk.iter().enumerate() {
+        let q = *keys
+            .entry(item.pubkey.to_compressed())
+            .or_insert_with(|| item.pubkey.to_affine().ok().map(ProjectivePoint::from));
+        let (Some(q), Some(r), Some(s)) = (q, scalar_from_bytes(&item.sig.r), scalar_from_bytes(&item.sig.s)) else { continue };
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(item.hash));
+        prepared.push(Prepared { index, q, r, s, z });
+    }
+    if prepared.is_empty() { return verdicts; }
+
+    // Every s is non-zero, so the batched inversion cannot fail
+    let s_values: Vec<Scalar> = prepared.iter().map(|p| p.s).collect();
+    let Some(w) = Option::<Vec<Scalar>>::from(<Scalar as BatchInvert<[Scalar]>>::batch_invert(&s_values)) else { return verdicts };
+
+    let points: Vec<ProjectivePoint> = prepared.iter().zip(&w)
+        .map(|(p, w)| ProjectivePoint::lincomb(&ProjectivePoint::GENERATOR, &(p.z * w), &p.q, &(p.r * w)))
+        .collect();
+    let affine = <ProjectivePoint as BatchNormalize<[ProjectivePoint]>>::batch_normalize(&points);
+    for (p, point) in prepared.iter().zip(affine) {
+        if bool::from(point.is_identity()) { continue; }
+        verdicts[p.index] = <Scalar as Reduce<U256>>::reduce_bytes(&point.x()) == p.r;
+    }
+    verdicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{sign_hash, verify_hash, PrivateKey};
+
+    #[test]
+    fn batch_matches_single_verification() {
+        let keys: Vec<PrivateKey> = (1..=3u8).map(|i| PrivateKey::from_bytes(&[i; 32]).unwrap()).collect();
+        let pubs: Vec<PublicKey> = keys.iter().map(|k| k.to_public_key()).collect();
+        let mut items = Vec::new();
+        for i in 0..300usize {
+            let hash = [(i % 251) as u8; 32];
+            let signer = i % 3;
+            let sig = sign_hash(&keys[signer], &hash).unwrap();
+            // every seventh item is checked against the wrong key
+            let pubkey = if i % 7 == 0 { &pubs[(signer + 1) % 3] } else { &pubs[signer] };
+            items.push(BatchItem { pubkey, hash, sig });
+        }
+        let verdicts = verify_batch(&items);
+        assert_eq!(verdicts.len(), items.len());
+        for (item, ok) in items.iter().zip(&verdicts) {
+            assert_eq!(*ok, verify_hash(item.pubkey, &item.hash, &item.sig).unwrap());
+        }
+        assert!(verdicts.iter().any(|v| !v) && verdicts.iter().filter(|v| **v).count() > 250);
+    }
+
+    #[test]
+    fn malformed_items_are_false() {
+        let key = PrivateKey::from_bytes(&[9; 32]).unwrap();
+        let good = sign_hash(&key, &[1; 32]).unwrap();
+        let pk = key.to_public_key();
+        let invalid = PublicKey::from_bytes_unchecked([0u8; 33]);
+        let items = [
+            BatchItem { pubkey: &pk, hash: [1; 32], sig: Signature::new([0; 32], good.s) },
+            BatchItem { pubkey: &invalid, hash: [1; 32], sig: good },
+            BatchItem { pubkey: &pk, hash: [1; 32], sig: good },
+        ];
+        assert_eq!(verify_batch(&items), vec![false, false, true]);
+        assert!(verify_batch(&[]).is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+// secp256k1 ECDSA over 32-byte message digests
+use crate::error::{Result, SdkError};
+use crate::primitives::{point::curve_order, BigNumber, Drbg};
+use k256::ecdsa::hazmat::SignPrimitive;
+use k256::elliptic_curve::group::prime::PrimeCurveAffine;
+use k256::elliptic_curve::ops::{LinearCombination, Reduce};
+use k256::elliptic_curve::point::{AffineCoordinates, DecompressPoint};
+use k256::elliptic_curve::sec1::FromEncodedPoint;
+use k256::elliptic_curve::subtle::Choice;
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Sign a 32-byte digest with an RFC 6979 deterministic nonce drawn from [`Drbg`].
+/// Returns `(r, s)` as big-endian scalars with `s` normalized to the low half of the order,
+/// plus the recovery id matching the normalized signature.
+pub fn sign_hash(privkey: &[u8; 32], hash: &[u8; 32]) -> Result<([u8; 32], [u8; 32], u8)> {
+    let d = scalar_from_bytes(privkey).ok_or(SdkError::CryptoError("invalid private key"))?;
+    let z = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(*hash));
+    // RFC 6979 section 3.2: seed with int2octets(d) || bits2octets(h)
+    let mut drbg = Drbg::new(privkey, &z.to_bytes());
+    loop {
+        // k reveals the private key, so every copy of it is wiped
+        let mut candidate = Zeroizing::new([0u8; 32]);
+        candidate.copy_from_slice(&drbg.generate(32)?);
+        let Some(mut k) = scalar_from_bytes(&candidate) else { continue };
+        let signed = d.try_sign_prehashed(k, &z.to_bytes());
+        k.zeroize();
+        // r or s of zero: draw the next candidate, as ts-sdk does
+        if let Ok((sig, Some(recid))) = signed {
+            let (r, s) = sig.split_bytes();
+            return Ok((r.into(), s.into(), recid.to_byte()));
+        }
+    }
+}
+
+/// Recover the signer's public point from `(r, s)`, the digest and a recovery id.
+/// Bit 0 of the id selects the parity of R.y, bit 1 marks that R.x overflowed the order.
+pub fn recover_hash(hash: &[u8; 32], r: &[u8; 32], s: &[u8; 32], recovery_id: u8) -> Result<AffinePoint> {
+    if recovery_id > 3 { return Err(SdkError::InvalidArgument("recovery id must be 0..=3")); }
+    let (r_scalar, s_scalar) = match (scalar_from_bytes(r), scalar_from_bytes(s)) {
+        (Some(r), Some(s)) => (r, s),
+        _ => return Err(SdkError::CryptoError("signature scalar out of range")),
+    };
+    let mut x = BigNumber::from_bytes_be(r);
+    if recovery_id & 2 != 0 { x = &x + &curve_order(); }
+    let x_bytes = x.to_bytes_be_padded(32).map_err(|_| SdkError::CryptoError("recovered x out of range"))?;
+    let big_r = Option::<AffinePoint>::from(AffinePoint::decompress(
+        FieldBytes::from_slice(&x_bytes),
+        Choice::from(recovery_id & 1),
+    )).ok_or(SdkError::CryptoError("no curve point for r"))?;
+    let z = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(*hash));
+    let r_inv = Option::<Scalar>::from(r_scalar.invert()).ok_or(SdkError::CryptoError("r not invertible"))?;
+    // Q = r^-1 (sR - zG)
+    let q = ProjectivePoint::lincomb(&ProjectivePoint::from(big_r), &(s_scalar * r_inv), &ProjectivePoint::GENERATOR, &(-z * r_inv));
+    let q = q.to_affine();
+    if bool::from(q.is_identity()) { return Err(SdkError::CryptoError("recovered point at infinity")); }
+    Ok(q)
+}
+
+/// Verify `(r, s)` over a 32-byte digest against a SEC1-encoded public key.
+/// High-S signatures are accepted, matching ts-sdk and go-sdk verification.
+pub fn verify_hash(pubkey: &[u8], hash: &[u8; 32], r: &[u8; 32], s: &[u8; 32]) -> Result<bool> {
+    let q = decode_point(pubkey)?;
+    let (r, s) = match (scalar_from_bytes(r), scalar_from_bytes(s)) {
+        (Some(r), Some(s)) => (r, s),
+        _ => return Ok(false),
+    };
+    let z = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(*hash));
+    let w = Option::<Scalar>::from(s.invert()).ok_or(SdkError::CryptoError("s not invertible"))?;
+    let point = ProjectivePoint::lincomb(&ProjectivePoint::GENERATOR, &(z * w), &ProjectivePoint::from(q), &(r * w));
+    let affine = point.to_affine();
+    if bool::from(affine.is_identity()) { return Ok(false); }
+    Ok(<Scalar as Reduce<U256>>::reduce_bytes(&affine.x()) == r)
+}
+
+// Non-zero scalar strictly below the curve order
+pub(crate) fn scalar_from_bytes(bytes: &[u8; 32]) -> Option<Scalar> {
+    let s = Option::<Scalar>::from(Scalar::from_repr((*bytes).into()))?;
+    if bool::from(s.is_zero()) { None } else { Some(s) }
+}
+
+fn decode_point(bytes: &[u8]) -> Result<AffinePoint> {
+    let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| SdkError::InvalidArgument("invalid public key encoding"))?;
+    Option::from(AffinePoint::from_encoded_point(&encoded)).ok_or(SdkError::InvalidArgument("public key not on curve"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::sha256;
+    use crate::util::{hex_decode, hex_encode};
+
+    fn key_one() -> [u8; 32] { let mut k = [0u8; 32]; k[31] = 1; k }
+
+    // G in compressed SEC1 form (public key for d = 1)
+    const G_COMPRESSED: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn rfc6979_vector_matches() {
+        // bitcoinjs / trezor fixture: d = 1, m = "Satoshi Nakamoto"
+        let crate::crypto::Sha256(h) = sha256(b"Satoshi Nakamoto");
+        let (r, s, _) = sign_hash(&key_one(), &h).unwrap();
+        assert_eq!(hex_encode(&r), "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8");
+        assert_eq!(hex_encode(&s), "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5");
+        let pk = hex_decode(G_COMPRESSED).unwrap();
+        assert!(verify_hash(&pk, &h, &r, &s).unwrap());
+    }
+
+    #[test]
+    fn verify_rejects_wrong_digest_and_accepts_high_s() {
+        let crate::crypto::Sha256(h) = sha256(b"msg");
+        let (r, s, _) = sign_hash(&key_one(), &h).unwrap();
+        let pk = hex_decode(G_COMPRESSED).unwrap();
+        let crate::crypto::Sha256(other) = sha256(b"other");
+        assert!(!verify_hash(&pk, &other, &r, &s).unwrap());
+        let high_s: [u8; 32] = (-scalar_from_bytes(&s).unwrap()).to_bytes().into();
+        assert!(verify_hash(&pk, &h, &r, &high_s).unwrap());
+    }
+
+    #[test]
+    fn recovery_returns_signer() {
+        let key = [0x5au8; 32];
+        let crate::crypto::Sha256(h) = sha256(b"recover me");
+        let (r, s, recid) = sign_hash(&key, &h).unwrap();
+        let expected = ProjectivePoint::GENERATOR * Scalar::from_repr(key.into()).unwrap();
+        assert_eq!(ProjectivePoint::from(recover_hash(&h, &r, &s, recid).unwrap()), expected);
+        let wrong = recover_hash(&h, &r, &s, recid ^ 1).unwrap();
+        assert_ne!(ProjectivePoint::from(wrong), expected);
+    }
+}
@@ -0,0 +1,129 @@
+// Streaming hashers, HMAC and PBKDF2
+use super::{Sha1, Sha256, Sha512};
+use hmac::{Hmac, Mac};
+use sha2::Digest as _;
+
+#[derive(Debug, Clone, Default)]
+pub struct Sha256Hasher(sha2::Sha256);
+
+impl Sha256Hasher {
+    pub fn new() -> Self { Self::default() }
+    pub fn update(&mut self, data: &[u8]) { self.0.update(data) }
+    pub fn finalize(self) -> Sha256 { Sha256(self.0.finalize().into()) }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Sha512Hasher(sha2::Sha512);
+
+impl Sha512Hasher {
+    pub fn new() -> Self { Self::default() }
+    pub fn update(&mut self, data: &[u8]) { self.0.update(data) }
+    pub fn finalize(self) -> Sha512 { Sha512(self.0.finalize().into()) }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Sha1Hasher(sha1::Sha1);
+
+impl Sha1Hasher {
+    pub fn new() -> Self { Self::default() }
+    pub fn update(&mut self, data: &[u8]) { sha1::Digest::update(&mut self.0, data) }
+    pub fn finalize(self) -> Sha1 { Sha1(sha1::Digest::finalize(self.0).into()) }
+}
+
+/// Streaming HMAC-SHA256; keys of any length are accepted
+#[derive(Clone)]
+pub struct HmacSha256(Hmac<sha2::Sha256>);
+
+impl HmacSha256 {
+    pub fn new(key: &[u8]) -> Self {
+        // HMAC is defined for every key length, so this cannot fail
+        Self(Hmac::new_from_slice(key).expect("hmac accepts any key length"))
+    }
+    pub fn update(&mut self, data: &[u8]) { self.0.update(data) }
+    pub fn finalize(self) -> [u8; 32] { self.0.finalize().into_bytes().into() }
+}
+
+/// Streaming HMAC-SHA512; keys of any length are accepted
+#[derive(Clone)]
+pub struct HmacSha512(Hmac<sha2::Sha512>);
+
+impl HmacSha512 {
+    pub fn new(key: &[u8]) -> Self {
+        Self(Hmac::new_from_slice(key).expect("hmac accepts any key length"))
+    }
+    pub fn update(&mut self, data: &[u8]) { self.0.update(data) }
+    pub fn finalize(self) -> [u8; 64] { self.0.finalize().into_bytes().into() }
+}
+
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = HmacSha256::new(key);
+    mac.update(data);
+    mac.finalize()
+}
+
+pub fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut mac = HmacSha512::new(key);
+    mac.update(data);
+    mac.finalize()
+}
+
+/// PBKDF2-HMAC-SHA256 filling `out` entirely
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password, salt, iterations, out)
+}
+
+/// PBKDF2-HMAC-SHA512 filling `out` entirely (BIP39 uses 2048 iterations, 64 bytes)
+pub fn pbkdf2_hmac_sha512(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    pbkdf2::pbkdf2_hmac::<sha2::Sha512>(password, salt, iterations, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{sha1, sha256, sha512};
+    use crate::util::hex_encode;
+
+    #[test]
+    fn digests_match_reference() {
+        assert_eq!(hex_encode(&sha1(b"abc").0), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            hex_encode(&sha512(b"abc").0),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+    }
+
+    #[test]
+    fn streaming_equals_one_shot() {
+        let mut h = Sha256Hasher::new();
+        h.update(b"hello ");
+        h.update(b"world");
+        assert_eq!(h.finalize().0, sha256(b"hello world").0);
+        let mut h = Sha512Hasher::new();
+        h.update(b"ab");
+        h.update(b"c");
+        assert_eq!(h.finalize().0, sha512(b"abc").0);
+    }
+
+    #[test]
+    fn hmac_rfc4231_case2() {
+        let (key, data) = (b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(hex_encode(&hmac_sha256(key, data)), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        assert_eq!(
+            hex_encode(&hmac_sha512(key, data)),
+            "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_vectors() {
+        let mut out = [0u8; 32];
+        pbkdf2_hmac_sha256(b"password", b"salt", 1, &mut out);
+        assert_eq!(hex_encode(&out), "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b");
+        let mut out = [0u8; 64];
+        pbkdf2_hmac_sha512(b"password", b"salt", 1, &mut out);
+        assert_eq!(
+            hex_encode(&out),
+            "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce"
+        );
+    }
+}
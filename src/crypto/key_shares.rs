@@ -0,0 +1,187 @@
+// Shamir secret sharing of private keys (ts-sdk: PrivateKey.toKeyShares / KeyShares)
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+use super::{hash160, hmac_sha512, Hash160, PrivateKey, RandomSource};
+use crate::error::{Result, SdkError};
+use crate::primitives::polynomial::{field_prime, PointInFiniteField, Polynomial};
+use crate::primitives::BigNumber;
+use crate::util::hex_encode;
+use core::fmt;
+
+/// Shares of a private key plus the metadata needed to reassemble and check it.
+/// Any `threshold` of the points is the key, so `Debug` shows only the metadata.
+#[derive(Clone)]
+pub struct KeyShares {
+    pub points: Vec<PointInFiniteField>,
+    pub threshold: usize,
+    /// First 8 hex chars of `hash160(key)`, used to detect a wrong reconstruction
+    pub integrity: String,
+}
+
+impl fmt::Debug for KeyShares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyShares")
+            .field("threshold", &self.threshold)
+            .field("integrity", &self.integrity)
+            .field("shares", &self.points.len())
+            .finish()
+    }
+}
+
+impl KeyShares {
+    pub fn new(points: Vec<PointInFiniteField>, threshold: usize, integrity: String) -> Self {
+        Self { points, threshold, integrity }
+    }
+
+    /// One printable `x.y.threshold.integrity` string per share
+    pub fn to_backup_format(&self) -> Vec<String> {
+        self.points.iter()
+            .map(|p| format!("{}.{}.{}", p.to_share_string(), self.threshold, self.integrity))
+            .collect()
+    }
+
+    /// Parse backup strings; all shares must agree on threshold and integrity
+    pub fn from_backup_format<S: AsRef<str>>(shares: &[S]) -> Result<Self> {
+        let mut points = Vec::with_capacity(shares.len());
+        let mut meta: Option<(usize, String)> = None;
+        for share in shares {
+            let parts: Vec<&str> = share.as_ref().split('.').collect();
+            if parts.len() != 4 { return Err(SdkError::ParseError("share must be x.y.threshold.integrity")); }
+            let threshold: usize = parts[2].parse().map_err(|_| SdkError::ParseError("invalid share threshold"))?;
+            let integrity = parts[3].to_string();
+            match &meta {
+                Some((t, i)) if *t != threshold || *i != integrity => {
+                    return Err(SdkError::InvalidArgument("shares disagree on threshold or integrity"));
+                }
+                Some(_) => {}
+                None => meta = Some((threshold, integrity)),
+            }
+            points.push(PointInFiniteField::from_share_string(&format!("{}.{}", parts[0], parts[1]))?);
+        }
+        let (threshold, integrity) = meta.ok_or(SdkError::InvalidArgument("no shares provided"))?;
+        Ok(Self { points, threshold, integrity })
+    }
+}
+
+fn integrity_of(key: &PrivateKey) -> String {
+    // ts-sdk hashes the minimal big-endian form of the key
+    let Hash160(h) = hash160(&BigNumber::from_bytes_be(key.as_bytes()).to_bytes_be());
+    hex_encode(&h)[..8].to_string()
+}
+
+impl PrivateKey {
+    /// Split into `total` shares, any `threshold` of which recover the key
+    pub fn to_key_shares<R: RandomSource + ?Sized>(&self, threshold: usize, total: usize, rng: &mut R) -> Result<KeyShares> {
+        if threshold < 2 { return Err(SdkError::InvalidArgument("threshold must be at least 2")); }
+        if total < 2 { return Err(SdkError::InvalidArgument("total shares must be at least 2")); }
+        if threshold > total { return Err(SdkError::InvalidArgument("threshold must not exceed total shares")); }
+        if total > 255 { return Err(SdkError::InvalidArgument("at most 255 shares are supported")); }
+
+        let secret = BigNumber::from_bytes_be(self.as_bytes());
+        let poly = Polynomial::from_secret(&secret, threshold, rng)?;
+        let p = field_prime();
+        let mut seed = [0u8; 64];
+        rng.fill_bytes(&mut seed)?;
+        let mut points: Vec<PointInFiniteField> = Vec::with_capacity(total);
+        for i in 0..total {
+            let mut attempts = 0u8;
+            let x = loop {
+                if attempts >= 5 { return Err(SdkError::CryptoError("failed to generate a unique share x coordinate")); }
+                let mut counter = vec![i as u8, attempts];
+                let mut salt = [0u8; 32];
+                rng.fill_bytes(&mut salt)?;
+                counter.extend_from_slice(&salt);
+                let x = BigNumber::from_bytes_be(&hmac_sha512(&seed, &counter)).umod(&p)?;
+                attempts += 1;
+                if !x.is_zero() && points.iter().all(|pt| pt.x != x) { break x; }
+            };
+            let y = poly.value_at(&x)?;
+            points.push(PointInFiniteField::new(x, y));
+        }
+        Ok(KeyShares::new(points, threshold, integrity_of(self)))
+    }
+
+    /// Reassemble a key from at least `threshold` distinct shares and check its integrity hash
+    pub fn from_key_shares(shares: &KeyShares) -> Result<Self> {
+        if shares.threshold < 2 || shares.threshold > 99 { return Err(SdkError::InvalidArgument("threshold must be between 2 and 99")); }
+        if shares.points.len() < shares.threshold { return Err(SdkError::InvalidArgument("not enough shares to meet threshold")); }
+        for (i, a) in shares.points.iter().enumerate() {
+            if shares.points[i + 1..].iter().any(|b| b.x == a.x) { return Err(SdkError::InvalidArgument("duplicate share")); }
+        }
+        let poly = Polynomial::new(shares.points.clone(), shares.threshold);
+        let secret = poly.value_at(&BigNumber::zero())?;
+        let bytes: [u8; 32] = secret.to_bytes_be_padded(32)?.try_into().map_err(|_| SdkError::CryptoError("secret length"))?;
+        let key = PrivateKey::from_bytes(&bytes)?;
+        if integrity_of(&key) != shares.integrity { return Err(SdkError::CryptoError("key share integrity check failed")); }
+        Ok(key)
+    }
+
+    pub fn to_backup_shares<R: RandomSource + ?Sized>(&self, threshold: usize, total: usize, rng: &mut R) -> Result<Vec<String>> {
+        Ok(self.to_key_shares(threshold, total, rng)?.to_backup_format())
+    }
+
+    pub fn from_backup_shares<S: AsRef<str>>(shares: &[S]) -> Result<Self> {
+        Self::from_key_shares(&KeyShares::from_backup_format(shares)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // xorshift so share generation is reproducible
+    struct XorShift(u64);
+    impl RandomSource for XorShift {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()> {
+            for b in dest.iter_mut() {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                *b = self.0 as u8;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn any_threshold_subset_recovers() {
+        let key = PrivateKey::from_bytes(&[0x3c; 32]).unwrap();
+        let backup = key.to_backup_shares(3, 5, &mut XorShift(42)).unwrap();
+        assert_eq!(backup.len(), 5);
+        assert!(backup.iter().all(|s| s.ends_with(&format!(".3.{}", integrity_of(&key)))));
+        assert_eq!(PrivateKey::from_backup_shares(&backup[..3]).unwrap(), key);
+        assert_eq!(PrivateKey::from_backup_shares(&[&backup[4], &backup[1], &backup[2]]).unwrap(), key);
+    }
+
+    #[test]
+    fn debug_hides_share_values() {
+        let shares = PrivateKey::from_bytes(&[0x3c; 32]).unwrap().to_key_shares(2, 3, &mut XorShift(5)).unwrap();
+        let shown = format!("{:?}", shares);
+        assert_eq!(shown, format!("KeyShares {{ threshold: 2, integrity: \"{}\", shares: 3 }}", shares.integrity));
+        assert!(shares.points.iter().all(|p| !shown.contains(&p.y.to_string())));
+    }
+
+    #[test]
+    fn too_few_or_tampered_shares_fail() {
+        let key = PrivateKey::from_bytes(&[0x11; 32]).unwrap();
+        let shares = key.to_key_shares(2, 3, &mut XorShift(7)).unwrap();
+        let one = KeyShares::new(shares.points[..1].to_vec(), 2, shares.integrity.clone());
+        assert!(PrivateKey::from_key_shares(&one).is_err());
+        let mut bad = shares.clone();
+        bad.points[0].y = &bad.points[0].y + &BigNumber::one();
+        assert!(PrivateKey::from_key_shares(&bad).is_err());
+        let mut dup = shares.clone();
+        dup.points[1] = dup.points[0].clone();
+        assert!(PrivateKey::from_key_shares(&dup).is_err());
+        assert!(key.to_key_shares(1, 3, &mut XorShift(7)).is_err());
+        assert!(key.to_key_shares(4, 3, &mut XorShift(7)).is_err());
+    }
+
+    #[test]
+    fn backup_format_must_agree() {
+        let key = PrivateKey::from_bytes(&[0x22; 32]).unwrap();
+        let mut backup = key.to_backup_shares(2, 2, &mut XorShift(3)).unwrap();
+        backup[1] = backup[1].replace(".2.", ".3.");
+        assert!(KeyShares::from_backup_format(&backup).is_err());
+        assert!(KeyShares::from_backup_format(&["a.b.c"]).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+// secp256k1 private/public keys
+use alloc::vec::Vec;
+use crate::error::{Result, SdkError};
+use super::random::RandomSource;
+use super::{hash160, Hash160};
+use crate::primitives::Point;
+use crate::util::hex_encode;
+use core::fmt;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+use k256::elliptic_curve::ops::MulByGenerator;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, EncodedPoint, ProjectivePoint, Scalar};
+
+/// secp256k1 secret scalar, guaranteed to be in `[1, n-1]`.
+/// The bytes are wiped on drop, compared in constant time and never printed by `Debug`.
+#[derive(Clone)]
+pub struct PrivateKey([u8; 32]);
+
+impl Drop for PrivateKey {
+    fn drop(&mut self) { self.0.zeroize() }
+}
+
+impl PartialEq for PrivateKey {
+    fn eq(&self, other: &Self) -> bool { self.0.ct_eq(&other.0).into() }
+}
+impl Eq for PrivateKey {}
+
+impl fmt::Debug for PrivateKey {
+    // Identify the key by the hash160 prefix of its public key, as BIP32 fingerprints do
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Hash160(h) = hash160(self.to_public_key().as_bytes());
+        write!(f, "PrivateKey(<redacted> fingerprint={})", hex_encode(&h[..4]))
+    }
+}
+
+impl PrivateKey {
+    /// Accept 32 big-endian bytes if they encode a valid non-zero scalar below the curve order
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self> {
+        let scalar = Option::<Scalar>::from(Scalar::from_repr((*bytes).into()))
+            .ok_or(SdkError::InvalidArgument("private key not below curve order"))?;
+        if bool::from(scalar.is_zero()) { return Err(SdkError::InvalidArgument("private key is zero")); }
+        Ok(Self(*bytes))
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        let arr: &[u8; 32] = bytes.try_into().map_err(|_| SdkError::InvalidArgument("private key must be 32 bytes"))?;
+        Self::from_bytes(arr)
+    }
+
+    /// Draw a uniformly random key by rejection sampling from `rng`. A healthy source is
+    /// rejected with probability about 2^-128, so a run of failures means the source is broken.
+    pub fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Result<Self> {
+        let mut buf = [0u8; 32];
+        for _ in 0..16 {
+            rng.fill_bytes(&mut buf)?;
+            let key = Self::from_bytes(&buf);
+            buf.zeroize();
+            if key.is_ok() { return key; }
+        }
+        Err(SdkError::CryptoError("random source keeps producing invalid private keys"))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] { self.0 }
+    pub fn as_bytes(&self) -> &[u8; 32] { &self.0 }
+
+    pub(crate) fn scalar(&self) -> Scalar {
+        // Range was checked on construction
+        Scalar::from_repr(self.0.into()).unwrap()
+    }
+
+    pub fn to_public_key(&self) -> PublicKey {
+        // mul_by_generator uses the fixed-base window table when `precomputed-tables` is on
+        PublicKey::from_affine(&ProjectivePoint::mul_by_generator(&self.scalar()).to_affine())
+    }
+
+    /// ECDH: multiply the counterparty's public point by this key
+    pub fn derive_shared_secret(&self, counterparty: &PublicKey) -> Result<Point> {
+        let q = ProjectivePoint::from(counterparty.to_affine()?);
+        Ok(Point::from_projective(q * self.scalar()))
+    }
+}
+
+/// secp256k1 public key, stored in compressed SEC1 form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey([u8; 33]);
+
+impl PublicKey {
+    /// Parse a compressed (33-byte) or uncompressed (65-byte) SEC1 point, rejecting points off the curve
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != 33 && bytes.len() != 65 { return Err(SdkError::InvalidArgument("public key must be 33 or 65 bytes")); }
+        let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| SdkError::InvalidArgument("invalid public key encoding"))?;
+        let point = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+            .ok_or(SdkError::InvalidArgument("public key not on curve"))?;
+        Ok(Self::from_affine(&point))
+    }
+
+    // Wraps bytes without validation so tests can exercise invalid keys
+    #[cfg(test)]
+    pub(crate) fn from_bytes_unchecked(bytes: [u8; 33]) -> Self { Self(bytes) }
+
+    pub(crate) fn from_affine(point: &AffinePoint) -> Self {
+        let mut out = [0u8; 33];
+        out.copy_from_slice(point.to_encoded_point(true).as_bytes());
+        Self(out)
+    }
+
+    pub(crate) fn to_affine(&self) -> Result<AffinePoint> {
+        let encoded = EncodedPoint::from_bytes(self.0).map_err(|_| SdkError::InvalidArgument("invalid public key encoding"))?;
+        Option::from(AffinePoint::from_encoded_point(&encoded)).ok_or(SdkError::InvalidArgument("public key not on curve"))
+    }
+
+    pub fn to_compressed(&self) -> [u8; 33] { self.0 }
+    pub fn as_bytes(&self) -> &[u8; 33] { &self.0 }
+
+    pub fn to_uncompressed(&self) -> Result<[u8; 65]> {
+        let mut out = [0u8; 65];
+        out.copy_from_slice(self.to_affine()?.to_encoded_point(false).as_bytes());
+        Ok(out)
+    }
+
+    /// SEC1 bytes in the requested form
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Result<Vec<u8>> {
+        if compressed { Ok(self.0.to_vec()) } else { Ok(self.to_uncompressed()?.to_vec()) }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyPair { pub private: PrivateKey, pub public: PublicKey }
+
+impl KeyPair {
+    pub fn from_private(private: PrivateKey) -> Self {
+        let public = private.to_public_key();
+        Self { private, public }
+    }
+
+    pub fn generate<R: RandomSource + ?Sized>(rng: &mut R) -> Result<Self> {
+        Ok(Self::from_private(PrivateKey::random(rng)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::{hex_decode, hex_encode};
+
+    // Counter-based source so key generation is reproducible
+    struct Counter(u8);
+    impl RandomSource for Counter {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()> {
+            for b in dest.iter_mut() { *b = self.0; }
+            self.0 = self.0.wrapping_add(1);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn from_bytes_checks_range() {
+        assert!(PrivateKey::from_bytes(&[0u8; 32]).is_err());
+        assert!(PrivateKey::from_bytes(&[0xffu8; 32]).is_err());
+        let n = hex_decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141").unwrap();
+        assert!(PrivateKey::from_slice(&n).is_err());
+        let mut n_minus_1: [u8; 32] = n.try_into().unwrap();
+        n_minus_1[31] -= 1;
+        assert!(PrivateKey::from_bytes(&n_minus_1).is_ok());
+    }
+
+    #[test]
+    fn generator_public_key_forms() {
+        let mut one = [0u8; 32]; one[31] = 1;
+        let pk = PrivateKey::from_bytes(&one).unwrap().to_public_key();
+        assert_eq!(hex_encode(pk.as_bytes()), "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+        let full = pk.to_uncompressed().unwrap();
+        assert_eq!(hex_encode(&full[33..]), "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+        assert_eq!(PublicKey::from_sec1_bytes(&full).unwrap(), pk);
+    }
+
+    #[test]
+    fn rejects_points_off_curve() {
+        let mut one = [0u8; 32]; one[31] = 1;
+        let mut bad = PrivateKey::from_bytes(&one).unwrap().to_public_key().to_uncompressed().unwrap();
+        bad[64] ^= 0x01;
+        assert!(PublicKey::from_sec1_bytes(&bad).is_err());
+        assert!(PublicKey::from_sec1_bytes(&[0x02; 20]).is_err());
+    }
+
+    #[test]
+    fn debug_redacts_secret() {
+        let key = PrivateKey::from_bytes(&[0xab; 32]).unwrap();
+        let kp = KeyPair::from_private(key.clone());
+        let printed = format!("{:?}", kp);
+        assert!(!printed.contains(&hex_encode(key.as_bytes())));
+        assert!(!printed.contains("171")); // 0xab as a derived array Debug would print it
+        assert!(printed.contains("<redacted> fingerprint="));
+        assert_eq!(key, kp.private);
+        assert_ne!(key, PrivateKey::from_bytes(&[0xac; 32]).unwrap());
+    }
+
+    #[test]
+    fn generate_is_deterministic_with_injected_rng() {
+        // 0x00.. is rejected, so the second draw (0x01..) is used
+        let kp = KeyPair::generate(&mut Counter(0)).unwrap();
+        assert_eq!(kp.private.to_bytes(), [1u8; 32]);
+        assert_eq!(KeyPair::generate(&mut Counter(0)).unwrap().public, kp.public);
+        // a stuck source gives up instead of spinning forever
+        struct Stuck;
+        impl RandomSource for Stuck {
+            fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()> { dest.fill(0); Ok(()) }
+        }
+        assert!(matches!(PrivateKey::random(&mut Stuck), Err(SdkError::CryptoError(_))));
+    }
+}
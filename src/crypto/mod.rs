@@ -1,17 +1,24 @@
 // Cryptographic primitives (placeholders)
-use crate::error::{Result, SdkError};
-use sha2::{Digest as _, Sha256 as Sha2};
+use crate::error::Result;
+use sha2::{Digest as _, Sha256 as Sha2, Sha512 as Sha2_512};
 use ripemd::Ripemd160 as Ripemd;
 
-#[derive(Debug, Clone, Default)]
-pub struct PrivateKey(pub [u8; 32]);
-#[derive(Debug, Clone)]
-pub struct PublicKey(pub [u8; 33]); // compressed
-impl Default for PublicKey {
-    fn default() -> Self { Self([0u8; 33]) }
-}
-#[derive(Debug, Clone, Default)]
-pub struct KeyPair { pub private: PrivateKey, pub public: PublicKey }
+pub mod batch;
+pub mod ecdsa;
+pub mod hash;
+pub mod key_shares;
+pub mod keys;
+pub mod random;
+pub mod signature;
+
+pub use batch::{verify_batch, BatchItem};
+pub use hash::{hmac_sha256, hmac_sha512, pbkdf2_hmac_sha256, pbkdf2_hmac_sha512, HmacSha256, HmacSha512, Sha1Hasher, Sha256Hasher, Sha512Hasher};
+pub use key_shares::KeyShares;
+pub use keys::{KeyPair, PrivateKey, PublicKey};
+pub use random::RandomSource;
+pub use signature::Signature;
+#[cfg(feature = "std")]
+pub use random::OsRandom;
 
 #[derive(Debug, Clone, Default)]
 pub struct Sha256(pub [u8; 32]);
@@ -19,6 +26,13 @@ pub struct Sha256(pub [u8; 32]);
 pub struct Ripemd160(pub [u8; 20]);
 #[derive(Debug, Clone, Default)]
 pub struct Hash160(pub [u8; 20]);
+#[derive(Debug, Clone)]
+pub struct Sha512(pub [u8; 64]);
+impl Default for Sha512 {
+    fn default() -> Self { Self([0u8; 64]) }
+}
+#[derive(Debug, Clone, Default)]
+pub struct Sha1(pub [u8; 20]);
 
 pub fn sha256(data: &[u8]) -> Sha256 {
     let mut hasher = Sha2::new();
@@ -29,6 +43,22 @@ pub fn sha256(data: &[u8]) -> Sha256 {
     Sha256(bytes)
 }
 
+pub fn sha512(data: &[u8]) -> Sha512 {
+    let mut hasher = Sha2_512::new();
+    hasher.update(data);
+    let out = hasher.finalize();
+    let mut bytes = [0u8; 64];
+    bytes.copy_from_slice(&out);
+    Sha512(bytes)
+}
+
+/// SHA-1: only for legacy script opcodes (OP_SHA1) and interop, never for new designs
+pub fn sha1(data: &[u8]) -> Sha1 {
+    let mut hasher = Sha1Hasher::new();
+    hasher.update(data);
+    hasher.finalize()
+}
+
 pub fn ripemd160(data: &[u8]) -> Ripemd160 {
     let mut hasher = Ripemd::new();
     use ripemd::Digest as _;
@@ -52,6 +82,40 @@ pub fn sha256d(data: &[u8]) -> [u8; 32] {
     second
 }
 
-pub fn generate_keypair() -> Result<KeyPair> { Err(SdkError::NotImplemented("generate_keypair")) }
-pub fn sign(_privkey: &PrivateKey, _msg: &[u8]) -> Result<Vec<u8>> { Err(SdkError::NotImplemented("sign")) }
-pub fn verify(_pubkey: &PublicKey, _msg: &[u8], _sig: &[u8]) -> Result<bool> { Err(SdkError::NotImplemented("verify")) }
+/// Generate a key pair from the operating-system RNG
+#[cfg(feature = "std")]
+pub fn generate_keypair() -> Result<KeyPair> { KeyPair::generate(&mut OsRandom) }
+
+/// ECDSA-sign `sha256(msg)` (as ts-sdk `PrivateKey.sign`) with a low-S signature
+pub fn sign(privkey: &PrivateKey, msg: &[u8]) -> Result<Signature> {
+    let Sha256(hash) = sha256(msg);
+    sign_hash(privkey, &hash)
+}
+
+/// ECDSA-sign a precomputed 32-byte digest (e.g. a transaction sighash)
+pub fn sign_hash(privkey: &PrivateKey, hash: &[u8; 32]) -> Result<Signature> {
+    Ok(sign_hash_recoverable(privkey, hash)?.0)
+}
+
+/// Like [`sign_hash`], also returning the recovery id (0..=3) of the signature
+pub fn sign_hash_recoverable(privkey: &PrivateKey, hash: &[u8; 32]) -> Result<(Signature, u8)> {
+    let (r, s, recid) = ecdsa::sign_hash(privkey.as_bytes(), hash)?;
+    Ok((Signature::new(r, s), recid))
+}
+
+/// Verify a signature over `sha256(msg)`
+pub fn verify(pubkey: &PublicKey, msg: &[u8], sig: &Signature) -> Result<bool> {
+    let Sha256(hash) = sha256(msg);
+    verify_hash(pubkey, &hash, sig)
+}
+
+/// Verify a signature over a precomputed 32-byte digest
+pub fn verify_hash(pubkey: &PublicKey, hash: &[u8; 32], sig: &Signature) -> Result<bool> {
+    ecdsa::verify_hash(pubkey.as_bytes(), hash, &sig.r, &sig.s)
+}
+
+/// Recover the public key that produced `sig` over `msg_hash`
+pub fn recover_public_key(msg_hash: &[u8; 32], sig: &Signature, recovery_id: u8) -> Result<PublicKey> {
+    let point = ecdsa::recover_hash(msg_hash, &sig.r, &sig.s, recovery_id)?;
+    Ok(PublicKey::from_affine(&point))
+}
@@ -0,0 +1,21 @@
+// Randomness sources behind a trait so callers can inject their own (HSMs, tests)
+use crate::error::Result;
+#[cfg(feature = "std")]
+use crate::error::SdkError;
+
+pub trait RandomSource {
+    /// Fill `dest` entirely with random bytes
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()>;
+}
+
+/// Operating-system CSPRNG (getrandom)
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsRandom;
+
+#[cfg(feature = "std")]
+impl RandomSource for OsRandom {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()> {
+        getrandom::getrandom(dest).map_err(|_| SdkError::CryptoError("os randomness unavailable"))
+    }
+}
@@ -0,0 +1,170 @@
+// ECDSA signature: strict DER (BIP66) and compact encodings
+use alloc::vec::Vec;
+use crate::error::{Result, SdkError};
+use k256::elliptic_curve::scalar::IsHigh;
+use k256::elliptic_curve::PrimeField;
+use k256::Scalar;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature { pub r: [u8; 32], pub s: [u8; 32] }
+
+impl Signature {
+    pub fn new(r: [u8; 32], s: [u8; 32]) -> Self { Self { r, s } }
+
+    /// Parse DER, enforcing the BIP66 strict-encoding rules (no sighash byte)
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        check_strict_der(der)?;
+        let len_r = der[3] as usize;
+        let r = int_to_32(&der[4..4 + len_r])?;
+        let s = int_to_32(&der[6 + len_r..])?;
+        Ok(Self { r, s })
+    }
+
+    /// DER-encode as `30 len 02 len r 02 len s` with minimal integers
+    pub fn to_der(&self) -> Vec<u8> {
+        let r = der_integer(&self.r);
+        let s = der_integer(&self.s);
+        let mut out = Vec::with_capacity(6 + r.len() + s.len());
+        out.push(0x30);
+        out.push((4 + r.len() + s.len()) as u8);
+        out.push(0x02);
+        out.push(r.len() as u8);
+        out.extend_from_slice(&r);
+        out.push(0x02);
+        out.push(s.len() as u8);
+        out.extend_from_slice(&s);
+        out
+    }
+
+    /// 64-byte `r || s`
+    pub fn from_compact(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != 64 { return Err(SdkError::InvalidArgument("compact signature must be 64 bytes")); }
+        let mut r = [0u8; 32]; r.copy_from_slice(&bytes[..32]);
+        let mut s = [0u8; 32]; s.copy_from_slice(&bytes[32..]);
+        Ok(Self { r, s })
+    }
+
+    pub fn to_compact(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.r);
+        out[32..].copy_from_slice(&self.s);
+        out
+    }
+
+    /// 65-byte `header || r || s` where header = 27 + recovery id (+4 if the key is compressed)
+    pub fn to_compact_recoverable(&self, recovery_id: u8, compressed: bool) -> Result<[u8; 65]> {
+        if recovery_id > 3 { return Err(SdkError::InvalidArgument("recovery id must be 0..=3")); }
+        let mut out = [0u8; 65];
+        out[0] = 27 + recovery_id + if compressed { 4 } else { 0 };
+        out[1..].copy_from_slice(&self.to_compact());
+        Ok(out)
+    }
+
+    /// Inverse of [`Signature::to_compact_recoverable`]: returns `(signature, recovery_id, compressed)`
+    pub fn from_compact_recoverable(bytes: &[u8]) -> Result<(Self, u8, bool)> {
+        if bytes.len() != 65 { return Err(SdkError::InvalidArgument("recoverable signature must be 65 bytes")); }
+        let header = bytes[0];
+        if !(27..=34).contains(&header) { return Err(SdkError::InvalidArgument("invalid recovery header byte")); }
+        let compressed = header >= 31;
+        let recovery_id = (header - 27) & 3;
+        Ok((Self::from_compact(&bytes[1..])?, recovery_id, compressed))
+    }
+
+    /// True when `s <= n/2`, the BIP62/BIP146 canonical form
+    pub fn is_low_s(&self) -> bool {
+        match Option::<Scalar>::from(Scalar::from_repr(self.s.into())) {
+            Some(s) => !bool::from(s.is_high()),
+            None => false,
+        }
+    }
+
+    /// Replace `s` with `n - s` when it is in the upper half of the order
+    pub fn to_low_s(&self) -> Self {
+        match Option::<Scalar>::from(Scalar::from_repr(self.s.into())) {
+            Some(s) if bool::from(s.is_high()) => Self { r: self.r, s: (-s).to_bytes().into() },
+            _ => *self,
+        }
+    }
+}
+
+/// BIP66 `IsValidSignatureEncoding`, applied to a DER blob without the trailing sighash byte
+fn check_strict_der(sig: &[u8]) -> Result<()> {
+    let bad = |m| Err(SdkError::ParseError(m));
+    if sig.len() < 8 || sig.len() > 72 { return bad("der: bad length"); }
+    if sig[0] != 0x30 { return bad("der: missing sequence"); }
+    if sig[1] as usize != sig.len() - 2 { return bad("der: bad sequence length"); }
+    let len_r = sig[3] as usize;
+    if 5 + len_r >= sig.len() { return bad("der: r length overflows"); }
+    let len_s = sig[5 + len_r] as usize;
+    if len_r + len_s + 6 != sig.len() { return bad("der: lengths do not add up"); }
+    if sig[2] != 0x02 { return bad("der: r is not an integer"); }
+    if len_r == 0 { return bad("der: empty r"); }
+    if sig[4] & 0x80 != 0 { return bad("der: negative r"); }
+    if len_r > 1 && sig[4] == 0x00 && sig[5] & 0x80 == 0 { return bad("der: r has excess padding"); }
+    if sig[len_r + 4] != 0x02 { return bad("der: s is not an integer"); }
+    if len_s == 0 { return bad("der: empty s"); }
+    if sig[len_r + 6] & 0x80 != 0 { return bad("der: negative s"); }
+    if len_s > 1 && sig[len_r + 6] == 0x00 && sig[len_r + 7] & 0x80 == 0 { return bad("der: s has excess padding"); }
+    Ok(())
+}
+
+// Strip leading zeros, then re-add one if the high bit would read as negative
+fn der_integer(v: &[u8; 32]) -> Vec<u8> {
+    let start = v.iter().position(|b| *b != 0).unwrap_or(31);
+    let mut out = Vec::with_capacity(33);
+    if v[start] & 0x80 != 0 { out.push(0x00); }
+    out.extend_from_slice(&v[start..]);
+    out
+}
+
+fn int_to_32(mut v: &[u8]) -> Result<[u8; 32]> {
+    while v.len() > 1 && v[0] == 0 { v = &v[1..]; }
+    if v.len() > 32 { return Err(SdkError::ParseError("der: integer too large")); }
+    let mut out = [0u8; 32];
+    out[32 - v.len()..].copy_from_slice(v);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::hex_decode;
+
+    const DER: &str = "3045022100934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d802202442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5";
+
+    #[test]
+    fn der_roundtrip() {
+        let der = hex_decode(DER).unwrap();
+        let sig = Signature::from_der(&der).unwrap();
+        assert_eq!(sig.r[0], 0x93);
+        assert_eq!(sig.to_der(), der);
+        assert!(sig.is_low_s());
+    }
+
+    #[test]
+    fn strict_der_rejections() {
+        let der = hex_decode(DER).unwrap();
+        let mut trailing = der.clone(); trailing.push(0x01);
+        assert!(Signature::from_der(&trailing).is_err());
+        // r without its sign padding reads as negative
+        let mut neg = der.clone(); neg.remove(4); neg[3] = 0x20; neg[1] -= 1;
+        assert!(Signature::from_der(&neg).is_err());
+        // s with a redundant leading zero
+        let mut padded = der.clone(); padded.insert(39, 0x00); padded[38] = 0x21; padded[1] += 1;
+        assert!(Signature::from_der(&padded).is_err());
+        assert!(Signature::from_der(&der[..7]).is_err());
+    }
+
+    #[test]
+    fn compact_forms_and_low_s() {
+        let sig = Signature::from_der(&hex_decode(DER).unwrap()).unwrap();
+        assert_eq!(Signature::from_compact(&sig.to_compact()).unwrap(), sig);
+        let rec = sig.to_compact_recoverable(1, true).unwrap();
+        assert_eq!(rec[0], 32);
+        assert_eq!(Signature::from_compact_recoverable(&rec).unwrap(), (sig, 1, true));
+        assert!(Signature::from_compact_recoverable(&[0u8; 65]).is_err());
+        let high = Signature::new(sig.r, (-Scalar::from_repr(sig.s.into()).unwrap()).to_bytes().into());
+        assert!(!high.is_low_s());
+        assert_eq!(high.to_low_s(), sig);
+    }
+}
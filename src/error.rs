@@ -5,8 +5,56 @@ pub enum SdkError {
     InvalidArgument(&'static str),
     ParseError(&'static str),
     CryptoError(&'static str),
+    Base58(Base58Error),
+    ScriptError(&'static str),
     IoError,
     NetworkError,
 }
 
+impl core::fmt::Display for SdkError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SdkError::NotImplemented(m) => write!(f, "not implemented: {}", m),
+            SdkError::InvalidArgument(m) => write!(f, "invalid argument: {}", m),
+            SdkError::ParseError(m) => write!(f, "parse error: {}", m),
+            SdkError::CryptoError(m) => write!(f, "crypto error: {}", m),
+            SdkError::Base58(e) => write!(f, "base58check error: {}", e),
+            SdkError::ScriptError(m) => write!(f, "script error: {}", m),
+            SdkError::IoError => f.write_str("i/o error"),
+            SdkError::NetworkError => f.write_str("network error"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SdkError {}
+
+/// Why a Base58Check string (address, WIF, ...) was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base58Error {
+    InvalidCharacter,
+    TooShort,
+    BadChecksum,
+    /// Decoded fine, but the version byte is not one this field accepts
+    UnknownVersion(u8),
+    /// Decoded fine, but the payload has the wrong length
+    BadLength(usize),
+}
+
+impl core::fmt::Display for Base58Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Base58Error::InvalidCharacter => f.write_str("invalid base58 character"),
+            Base58Error::TooShort => f.write_str("too short"),
+            Base58Error::BadChecksum => f.write_str("checksum mismatch"),
+            Base58Error::UnknownVersion(v) => write!(f, "unknown version byte 0x{:02x}", v),
+            Base58Error::BadLength(n) => write!(f, "unexpected payload length {}", n),
+        }
+    }
+}
+
+impl From<Base58Error> for SdkError {
+    fn from(e: Base58Error) -> Self { SdkError::Base58(e) }
+}
+
 pub type Result<T> = core::result::Result<T, SdkError>;
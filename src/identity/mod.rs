@@ -1,7 +1,8 @@
 // Identity module (DID/keys/profile placeholders)
+use alloc::string::String;
 use crate::crypto::{PrivateKey, PublicKey};
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct Identity {
     pub id: String,
     pub pubkey: PublicKey,
@@ -12,5 +13,5 @@ pub struct IdentityManager;
 
 impl IdentityManager {
     pub fn new() -> Self { Self }
-    pub fn from_private_key(_pk: &PrivateKey) -> Identity { Identity { id: String::new(), pubkey: PublicKey([0u8; 33]) } }
+    pub fn from_private_key(pk: &PrivateKey) -> Identity { Identity { id: String::new(), pubkey: pk.to_public_key() } }
 }
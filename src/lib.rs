@@ -1,28 +1,42 @@
 // BSV Rust SDK (rs-sdk) - high-level module layout
-#![cfg_attr(not(feature = "std"), no_std)]
+// Without `std`, the core (util, crypto, primitives, script, transaction) builds on `alloc` only.
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
 
 pub mod prelude;
 pub mod error;
 
 pub mod util;
+pub mod chain_params;
 pub mod crypto;
-pub mod net; // behind trait; concrete impls optional via features
 pub mod address; // address + WIF helpers
 pub mod script; // top-level script module to mirror ts-sdk
 pub mod compat;
-pub mod kvstore;
 pub mod primitives;
+pub mod transaction;
+pub mod messages;
+pub mod identity;
+
+// I/O, storage and network modules need `std`
+#[cfg(feature = "std")]
+pub mod net; // behind trait; concrete impls optional via features
+#[cfg(feature = "std")]
+pub mod kvstore;
+#[cfg(feature = "std")]
 pub mod registry;
+#[cfg(feature = "std")]
 pub mod totp;
+#[cfg(feature = "std")]
 pub mod overlay_tools;
-
+#[cfg(feature = "std")]
 pub mod auth;
-pub mod identity;
+#[cfg(feature = "std")]
 pub mod overlay;
+#[cfg(feature = "std")]
 pub mod storage;
+#[cfg(feature = "std")]
 pub mod wallet;
-pub mod transaction;
-pub mod messages;
 
 // WASM bindings are optional
 #[cfg(feature = "wasm")]
@@ -32,4 +46,5 @@ pub mod wasm;
 pub fn version() -> &'static str { env!("CARGO_PKG_VERSION") }
 
 // Public re-exports for common types
-pub use error::SdkError;
+pub use chain_params::ChainParams;
+pub use error::{Base58Error, SdkError};
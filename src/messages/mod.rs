@@ -1,11 +1,34 @@
-// Signed/unencrypted messaging over BSV (placeholders)
-use crate::{crypto::{PrivateKey, PublicKey}, error::{Result, SdkError}};
+// Signed/unencrypted messaging over BSV
+use alloc::vec::Vec;
+use crate::compat::bsm::magic_hash;
+use crate::{crypto::{sign_hash, verify_hash, PrivateKey, PublicKey, Signature}, error::Result};
 
 #[derive(Debug, Default, Clone)]
 pub struct Message { pub data: Vec<u8> }
 
-#[derive(Debug, Default, Clone)]
-pub struct SignedMessage { pub data: Vec<u8>, pub signature: Vec<u8>, pub pubkey: PublicKey }
+#[derive(Debug, Clone)]
+pub struct SignedMessage { pub data: Vec<u8>, pub signature: Signature, pub pubkey: PublicKey }
+
+/// Sign the Bitcoin Signed Message hash of `msg.data`
+pub fn sign_message(msg: &Message, priv_key: &PrivateKey) -> Result<SignedMessage> {
+    let signature = sign_hash(priv_key, &magic_hash(&msg.data))?;
+    Ok(SignedMessage { data: msg.data.clone(), signature, pubkey: priv_key.to_public_key() })
+}
+
+pub fn verify_message(signed: &SignedMessage) -> Result<bool> {
+    verify_hash(&signed.pubkey, &magic_hash(&signed.data), &signed.signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub fn sign_message(_msg: &Message, _priv: &PrivateKey) -> Result<SignedMessage> { Err(SdkError::NotImplemented("sign_message")) }
-pub fn verify_message(_signed: &SignedMessage) -> Result<bool> { Err(SdkError::NotImplemented("verify_message")) }
+    #[test]
+    fn sign_then_verify() {
+        let key = PrivateKey::from_bytes(&[9; 32]).unwrap();
+        let mut signed = sign_message(&Message { data: b"hi".to_vec() }, &key).unwrap();
+        assert!(verify_message(&signed).unwrap());
+        signed.data.push(b'!');
+        assert!(!verify_message(&signed).unwrap());
+    }
+}
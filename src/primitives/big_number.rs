@@ -0,0 +1,235 @@
+// Arbitrary-precision signed integer (ts-sdk: primitives/BigNumber)
+use alloc::{string::String, vec, vec::Vec};
+use crate::error::{Result, SdkError};
+use crate::script::script_num;
+use core::cmp::Ordering;
+use core::ops::{Add, Mul, Neg, Sub};
+use num_bigint::{BigInt, Sign};
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use zeroize::Zeroize;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigNumber(BigInt);
+
+impl BigNumber {
+    pub fn zero() -> Self { Self(BigInt::zero()) }
+    pub fn one() -> Self { Self(BigInt::one()) }
+    pub fn from_i64(n: i64) -> Self { Self(BigInt::from(n)) }
+    pub fn from_u64(n: u64) -> Self { Self(BigInt::from(n)) }
+
+    /// Unsigned big-endian magnitude
+    pub fn from_bytes_be(bytes: &[u8]) -> Self { Self(BigInt::from_bytes_be(Sign::Plus, bytes)) }
+    /// Unsigned little-endian magnitude
+    pub fn from_bytes_le(bytes: &[u8]) -> Self { Self(BigInt::from_bytes_le(Sign::Plus, bytes)) }
+
+    /// Big-endian magnitude without leading zeros (empty for zero); the sign is dropped
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        if self.is_zero() { return Vec::new(); }
+        self.0.magnitude().to_bytes_be()
+    }
+
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        if self.is_zero() { return Vec::new(); }
+        self.0.magnitude().to_bytes_le()
+    }
+
+    /// Big-endian magnitude left-padded with zeros to exactly `len` bytes
+    pub fn to_bytes_be_padded(&self, len: usize) -> Result<Vec<u8>> {
+        let raw = self.to_bytes_be();
+        if raw.len() > len { return Err(SdkError::InvalidArgument("number does not fit in requested length")); }
+        let mut out = vec![0u8; len - raw.len()];
+        out.extend_from_slice(&raw);
+        Ok(out)
+    }
+
+    pub fn to_bytes_le_padded(&self, len: usize) -> Result<Vec<u8>> {
+        let mut out = self.to_bytes_be_padded(len)?;
+        out.reverse();
+        Ok(out)
+    }
+
+    /// Parse hex with an optional leading `-` (an odd digit count is allowed)
+    pub fn from_hex(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (neg, digits) = match s.strip_prefix('-') { Some(rest) => (true, rest), None => (false, s) };
+        if digits.is_empty() { return Err(SdkError::ParseError("empty hex number")); }
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) { return Err(SdkError::ParseError("invalid hex number")); }
+        let v = BigInt::parse_bytes(digits.as_bytes(), 16).ok_or(SdkError::ParseError("invalid hex number"))?;
+        Ok(Self(if neg { -v } else { v }))
+    }
+
+    /// Lowercase hex, `-` prefixed when negative; zero is `"0"`
+    pub fn to_hex(&self) -> String { self.0.to_str_radix(16) }
+
+    /// Decode a Bitcoin script number: little-endian sign-magnitude, sign in the top bit of the last byte.
+    /// With `require_minimal`, encodings carrying a redundant trailing byte are rejected.
+    pub fn from_script_num(bytes: &[u8], require_minimal: bool, max_num_size: Option<usize>) -> Result<Self> {
+        script_num::check_encoding(bytes, require_minimal, max_num_size.unwrap_or(usize::MAX)).map_err(SdkError::InvalidArgument)?;
+        let (magnitude, negative) = script_num::decode_magnitude(bytes);
+        let v = BigInt::from_bytes_le(Sign::Plus, &magnitude);
+        Ok(Self(if negative { -v } else { v }))
+    }
+
+    /// Encode as a minimal Bitcoin script number (zero is the empty vector)
+    pub fn to_script_num(&self) -> Vec<u8> {
+        script_num::encode_magnitude(self.to_bytes_le(), self.is_neg())
+    }
+
+    pub fn is_zero(&self) -> bool { self.0.is_zero() }
+    pub fn is_neg(&self) -> bool { self.0.is_negative() }
+    pub fn is_odd(&self) -> bool { self.0.is_odd() }
+    pub fn abs(&self) -> Self { Self(self.0.abs()) }
+    pub fn bit_length(&self) -> u64 { self.0.bits() }
+
+    /// Truncating division (rounds toward zero, like bn.js `div`)
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self> {
+        if rhs.is_zero() { return Err(SdkError::InvalidArgument("division by zero")); }
+        Ok(Self(&self.0 / &rhs.0))
+    }
+
+    /// Remainder carrying the sign of the dividend (bn.js `mod`)
+    pub fn checked_rem(&self, rhs: &Self) -> Result<Self> {
+        if rhs.is_zero() { return Err(SdkError::InvalidArgument("division by zero")); }
+        Ok(Self(&self.0 % &rhs.0))
+    }
+
+    /// Non-negative remainder in `[0, |m|)` (bn.js `umod`)
+    pub fn umod(&self, m: &Self) -> Result<Self> {
+        if m.is_zero() { return Err(SdkError::InvalidArgument("division by zero")); }
+        Ok(Self(self.0.mod_floor(&m.0.abs())))
+    }
+
+    pub fn pow(&self, exp: u32) -> Self { Self(num_traits::pow(self.0.clone(), exp as usize)) }
+
+    /// `self^exp mod m` for a non-negative exponent and positive modulus
+    pub fn pow_mod(&self, exp: &Self, m: &Self) -> Result<Self> {
+        if exp.is_neg() { return Err(SdkError::InvalidArgument("negative exponent")); }
+        if !m.0.is_positive() { return Err(SdkError::InvalidArgument("modulus must be positive")); }
+        Ok(Self(self.0.modpow(&exp.0, &m.0).mod_floor(&m.0)))
+    }
+
+    /// Modular inverse in `[0, m)`
+    pub fn invm(&self, m: &Self) -> Result<Self> {
+        if !m.0.is_positive() { return Err(SdkError::InvalidArgument("modulus must be positive")); }
+        self.0.mod_floor(&m.0).modinv(&m.0).map(Self).ok_or(SdkError::CryptoError("number is not invertible"))
+    }
+
+    pub fn cmp_i64(&self, n: i64) -> Ordering { self.0.cmp(&BigInt::from(n)) }
+
+    /// Narrow to `i64`, failing when out of range
+    pub fn to_i64(&self) -> Result<i64> {
+        i64::try_from(&self.0).map_err(|_| SdkError::InvalidArgument("number out of i64 range"))
+    }
+}
+
+/// Best effort: num-bigint exposes no mutable digits, so the value is overwritten with
+/// zeros in place (reusing its buffer) before being normalised to zero
+impl Zeroize for BigNumber {
+    fn zeroize(&mut self) {
+        let words = self.0.magnitude().iter_u32_digits().len();
+        self.0.assign_from_slice(Sign::Plus, &vec![0u32; words]);
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+impl From<i64> for BigNumber { fn from(n: i64) -> Self { Self::from_i64(n) } }
+impl From<u64> for BigNumber { fn from(n: u64) -> Self { Self::from_u64(n) } }
+
+impl core::fmt::Display for BigNumber {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { write!(f, "{}", self.0) }
+}
+
+macro_rules! forward_binop {
+    ($tr:ident, $m:ident) => {
+        impl $tr<&BigNumber> for &BigNumber {
+            type Output = BigNumber;
+            fn $m(self, rhs: &BigNumber) -> BigNumber { BigNumber($tr::$m(&self.0, &rhs.0)) }
+        }
+        impl $tr for BigNumber {
+            type Output = BigNumber;
+            fn $m(self, rhs: BigNumber) -> BigNumber { BigNumber($tr::$m(self.0, rhs.0)) }
+        }
+    };
+}
+forward_binop!(Add, add);
+forward_binop!(Sub, sub);
+forward_binop!(Mul, mul);
+
+impl Neg for BigNumber {
+    type Output = BigNumber;
+    fn neg(self) -> BigNumber { BigNumber(-self.0) }
+}
+impl Neg for &BigNumber {
+    type Output = BigNumber;
+    fn neg(self) -> BigNumber { BigNumber(-&self.0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bn(n: i64) -> BigNumber { BigNumber::from_i64(n) }
+
+    #[test]
+    fn arithmetic_does_not_overflow() {
+        let big = BigNumber::from_u64(u64::MAX);
+        let sq = &big * &big;
+        assert_eq!(sq.to_hex(), "fffffffffffffffe0000000000000001");
+        assert_eq!((&sq - &sq).to_hex(), "0");
+        assert_eq!((bn(-7) + bn(3)).to_i64().unwrap(), -4);
+        assert_eq!(bn(2).pow(100).bit_length(), 101);
+    }
+
+    #[test]
+    fn division_and_modulo_signs() {
+        assert_eq!(bn(-7).checked_div(&bn(2)).unwrap(), bn(-3));
+        assert_eq!(bn(-7).checked_rem(&bn(2)).unwrap(), bn(-1));
+        assert_eq!(bn(-7).umod(&bn(2)).unwrap(), bn(1));
+        assert!(bn(1).checked_div(&BigNumber::zero()).is_err());
+    }
+
+    #[test]
+    fn modular_inverse_and_pow() {
+        let p = bn(97);
+        let inv = bn(5).invm(&p).unwrap();
+        assert_eq!((&inv * &bn(5)).umod(&p).unwrap(), BigNumber::one());
+        assert_eq!(bn(-5).invm(&p).unwrap(), (-&inv).umod(&p).unwrap());
+        assert!(bn(6).invm(&bn(9)).is_err());
+        assert_eq!(bn(3).pow_mod(&bn(200), &p).unwrap(), bn(3).pow(200).umod(&p).unwrap());
+    }
+
+    #[test]
+    fn bytes_and_hex() {
+        let n = BigNumber::from_hex("-0102ff").unwrap();
+        assert!(n.is_neg());
+        assert_eq!(n.to_hex(), "-102ff");
+        assert_eq!(n.to_bytes_be(), vec![0x01, 0x02, 0xff]);
+        assert_eq!(BigNumber::from_bytes_le(&[0xff, 0x02, 0x01]), n.abs());
+        assert_eq!(n.to_bytes_be_padded(5).unwrap(), vec![0, 0, 1, 2, 0xff]);
+        assert_eq!(n.to_bytes_le_padded(4).unwrap(), vec![0xff, 2, 1, 0]);
+        assert!(n.to_bytes_be_padded(2).is_err());
+        assert!(BigNumber::from_hex("xyz").is_err());
+        let mut secret = BigNumber::from_hex("-1234567890abcdef1234567890abcdef").unwrap();
+        zeroize::Zeroize::zeroize(&mut secret);
+        assert!(secret.is_zero() && !secret.is_neg());
+        for bad in ["--5", "_", "-", "1_0", "+5", "-+5"] { assert!(BigNumber::from_hex(bad).is_err(), "{}", bad); }
+        assert_eq!(BigNumber::from_hex("-aBc").unwrap(), BigNumber::from_i64(-0xabc));
+    }
+
+    #[test]
+    fn script_num_encoding() {
+        let cases: &[(i64, &[u8])] = &[
+            (0, &[]), (1, &[0x01]), (-1, &[0x81]), (127, &[0x7f]), (128, &[0x80, 0x00]),
+            (-128, &[0x80, 0x80]), (255, &[0xff, 0x00]), (256, &[0x00, 0x01]), (-32768, &[0x00, 0x80, 0x80]),
+        ];
+        for (n, enc) in cases {
+            assert_eq!(bn(*n).to_script_num(), enc.to_vec(), "encode {}", n);
+            assert_eq!(BigNumber::from_script_num(enc, true, None).unwrap(), bn(*n), "decode {}", n);
+        }
+        assert!(BigNumber::from_script_num(&[0x01, 0x00], true, None).is_err());
+        assert!(BigNumber::from_script_num(&[0x80], true, None).is_err());
+        assert_eq!(BigNumber::from_script_num(&[0x01, 0x00], false, None).unwrap(), bn(1));
+        assert!(BigNumber::from_script_num(&[1, 2, 3, 4, 5], false, Some(4)).is_err());
+    }
+}
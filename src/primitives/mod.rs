@@ -1,2 +1,15 @@
-// primitives module (ts-sdk: primitives)
-// Placeholder for AESGCM, BigNumber, ECDSA, Hash, etc.
+// Big numbers, curve points, HMAC-DRBG, Shamir polynomials, Schnorr proofs and AES-GCM (ts-sdk: primitives)
+
+pub mod aes_gcm;
+pub mod big_number;
+pub mod drbg;
+pub mod point;
+pub mod polynomial;
+pub mod schnorr;
+pub mod symmetric_key;
+
+pub use big_number::BigNumber;
+pub use drbg::Drbg;
+pub use point::Point;
+pub use schnorr::{Schnorr, SchnorrProof};
+pub use symmetric_key::SymmetricKey;
@@ -0,0 +1,142 @@
+// secp256k1 curve point (ts-sdk: primitives/Point)
+use alloc::vec::Vec;
+use super::BigNumber;
+use crate::crypto::PublicKey;
+use crate::error::{Result, SdkError};
+use core::ops::{Add, Mul, Neg, Sub};
+use k256::elliptic_curve::group::prime::PrimeCurveAffine;
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, EncodedPoint, ProjectivePoint, Scalar};
+
+/// Curve order n, big-endian
+pub const CURVE_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Field prime p, big-endian
+pub const FIELD_PRIME: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xfc, 0x2f,
+];
+
+pub fn curve_order() -> BigNumber { BigNumber::from_bytes_be(&CURVE_ORDER) }
+
+/// Reduce any integer modulo n into a curve scalar
+pub(crate) fn scalar_from_big(k: &BigNumber) -> Scalar {
+    // umod(n) is in [0, n) and fits 32 bytes, so neither step can fail
+    let reduced = k.umod(&curve_order()).unwrap();
+    let bytes: [u8; 32] = reduced.to_bytes_be_padded(32).unwrap().try_into().unwrap();
+    Scalar::from_repr(bytes.into()).unwrap()
+}
+
+/// A point on secp256k1, including the point at infinity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point(ProjectivePoint);
+
+impl Point {
+    pub fn generator() -> Self { Self(ProjectivePoint::GENERATOR) }
+    pub fn infinity() -> Self { Self(ProjectivePoint::IDENTITY) }
+    pub fn is_infinity(&self) -> bool { self.0 == ProjectivePoint::IDENTITY }
+
+    /// Decode SEC1 (compressed, uncompressed, or `00` for infinity), rejecting points off the curve
+    pub fn from_sec1(bytes: &[u8]) -> Result<Self> {
+        let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| SdkError::InvalidArgument("invalid point encoding"))?;
+        let affine = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+            .ok_or(SdkError::InvalidArgument("point not on curve"))?;
+        Ok(Self(affine.into()))
+    }
+
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        self.0.to_affine().to_encoded_point(compressed).as_bytes().to_vec()
+    }
+
+    /// Affine x coordinate, `None` at infinity
+    pub fn x(&self) -> Option<BigNumber> {
+        let a = self.0.to_affine();
+        if bool::from(a.is_identity()) { return None; }
+        Some(BigNumber::from_bytes_be(&a.x()))
+    }
+
+    /// Affine y coordinate, `None` at infinity
+    pub fn y(&self) -> Option<BigNumber> {
+        let a = self.0.to_affine();
+        if bool::from(a.is_identity()) { return None; }
+        let full = a.to_encoded_point(false);
+        Some(BigNumber::from_bytes_be(&full.as_bytes()[33..]))
+    }
+
+    pub fn to_public_key(&self) -> Result<PublicKey> {
+        if self.is_infinity() { return Err(SdkError::InvalidArgument("point at infinity is not a public key")); }
+        Ok(PublicKey::from_affine(&self.0.to_affine()))
+    }
+
+    pub fn from_public_key(pk: &PublicKey) -> Result<Self> { Ok(Self(pk.to_affine()?.into())) }
+
+    pub(crate) fn from_projective(p: ProjectivePoint) -> Self { Self(p) }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, rhs: Point) -> Point { Point(self.0 + rhs.0) }
+}
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, rhs: Point) -> Point { Point(self.0 - rhs.0) }
+}
+impl Neg for Point {
+    type Output = Point;
+    fn neg(self) -> Point { Point(-self.0) }
+}
+impl Mul<&BigNumber> for Point {
+    type Output = Point;
+    fn mul(self, k: &BigNumber) -> Point { Point(self.0 * scalar_from_big(k)) }
+}
+
+impl TryFrom<&PublicKey> for Point {
+    type Error = SdkError;
+    fn try_from(pk: &PublicKey) -> Result<Self> { Self::from_public_key(pk) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::PrivateKey;
+    use crate::util::hex_encode;
+
+    #[test]
+    fn group_laws() {
+        let g = Point::generator();
+        let two = g + g;
+        assert_eq!(two, g * &BigNumber::from_i64(2));
+        assert_eq!(two - g, g);
+        assert!((g + (-g)).is_infinity());
+        assert!((g * &curve_order()).is_infinity());
+        assert_eq!(g * &BigNumber::from_i64(-1), -g);
+        assert_eq!(hex_encode(&g.x().unwrap().to_bytes_be()), "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+    }
+
+    #[test]
+    fn sec1_roundtrip_and_public_key() {
+        let p = Point::generator() * &BigNumber::from_i64(7);
+        for compressed in [true, false] {
+            assert_eq!(Point::from_sec1(&p.to_sec1(compressed)).unwrap(), p);
+        }
+        assert!(Point::from_sec1(&Point::infinity().to_sec1(true)).unwrap().is_infinity());
+        let pk = p.to_public_key().unwrap();
+        assert_eq!(Point::try_from(&pk).unwrap(), p);
+        assert!(Point::infinity().to_public_key().is_err());
+    }
+
+    #[test]
+    fn ecdh_is_symmetric() {
+        let a = PrivateKey::from_bytes(&[0x11; 32]).unwrap();
+        let b = PrivateKey::from_bytes(&[0x22; 32]).unwrap();
+        let ab = a.derive_shared_secret(&b.to_public_key()).unwrap();
+        let ba = b.derive_shared_secret(&a.to_public_key()).unwrap();
+        assert_eq!(ab, ba);
+        assert_eq!(ab, Point::generator() * &BigNumber::from_bytes_be(&[0x11; 32]) * &BigNumber::from_bytes_be(&[0x22; 32]));
+    }
+}
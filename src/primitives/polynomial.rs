@@ -0,0 +1,123 @@
+// Polynomials over the secp256k1 base field (ts-sdk: primitives/Polynomial)
+use alloc::{format, string::String, vec, vec::Vec};
+use super::point::FIELD_PRIME;
+use super::BigNumber;
+use crate::crypto::RandomSource;
+use crate::error::{Result, SdkError};
+use core::fmt;
+use zeroize::Zeroize;
+
+pub fn field_prime() -> BigNumber { BigNumber::from_bytes_be(&FIELD_PRIME) }
+
+/// A point `(x, y)` with coordinates reduced modulo p. As a key share, `y` is secret:
+/// it is wiped on drop and never printed by `Debug`.
+#[derive(Clone)]
+pub struct PointInFiniteField { pub x: BigNumber, pub y: BigNumber }
+
+impl Drop for PointInFiniteField {
+    fn drop(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+impl fmt::Debug for PointInFiniteField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PointInFiniteField").field("x", &self.x).field("y", &"<redacted>").finish()
+    }
+}
+
+impl PointInFiniteField {
+    pub fn new(x: BigNumber, y: BigNumber) -> Self {
+        let p = field_prime();
+        // p is positive, so umod cannot fail
+        Self { x: x.umod(&p).unwrap(), y: y.umod(&p).unwrap() }
+    }
+
+    /// `base58(x).base58(y)` using minimal big-endian bytes, as ts-sdk does
+    pub fn to_share_string(&self) -> String {
+        format!("{}.{}", base58_of(&self.x), base58_of(&self.y))
+    }
+
+    pub fn from_share_string(s: &str) -> Result<Self> {
+        let (x, y) = s.split_once('.').ok_or(SdkError::ParseError("share point must be x.y"))?;
+        Ok(Self::new(from_base58(x)?, from_base58(y)?))
+    }
+}
+
+fn base58_of(n: &BigNumber) -> String {
+    let bytes = n.to_bytes_be();
+    bs58::encode(if bytes.is_empty() { vec![0u8] } else { bytes }).into_string()
+}
+
+fn from_base58(s: &str) -> Result<BigNumber> {
+    let raw = bs58::decode(s).into_vec().map_err(|_| SdkError::ParseError("invalid base58 in share"))?;
+    Ok(BigNumber::from_bytes_be(&raw))
+}
+
+/// Degree `threshold - 1` polynomial given by `threshold` points (Lagrange form)
+#[derive(Debug, Clone)]
+pub struct Polynomial { pub points: Vec<PointInFiniteField>, pub threshold: usize }
+
+impl Polynomial {
+    pub fn new(points: Vec<PointInFiniteField>, threshold: usize) -> Self { Self { points, threshold } }
+
+    /// Random polynomial with `f(0) = secret`: the secret plus `threshold - 1` random points
+    pub fn from_secret<R: RandomSource + ?Sized>(secret: &BigNumber, threshold: usize, rng: &mut R) -> Result<Self> {
+        let mut points = vec![PointInFiniteField::new(BigNumber::zero(), secret.clone())];
+        let mut buf = [0u8; 32];
+        for _ in 1..threshold {
+            rng.fill_bytes(&mut buf)?;
+            let x = BigNumber::from_bytes_be(&buf);
+            rng.fill_bytes(&mut buf)?;
+            let y = BigNumber::from_bytes_be(&buf);
+            points.push(PointInFiniteField::new(x, y));
+        }
+        Ok(Self { points, threshold })
+    }
+
+    /// Lagrange interpolation at `x` over the first `threshold` points
+    pub fn value_at(&self, x: &BigNumber) -> Result<BigNumber> {
+        let p = field_prime();
+        if self.points.len() < self.threshold { return Err(SdkError::InvalidArgument("not enough points for threshold")); }
+        let pts = &self.points[..self.threshold];
+        let mut y = BigNumber::zero();
+        for (i, pi) in pts.iter().enumerate() {
+            let mut term = pi.y.clone();
+            for (j, pj) in pts.iter().enumerate() {
+                if i == j { continue; }
+                let num = (x - &pj.x).umod(&p)?;
+                let den = (&pi.x - &pj.x).umod(&p)?.invm(&p)?;
+                term = (&term * &(&num * &den).umod(&p)?).umod(&p)?;
+            }
+            y = (&y + &term).umod(&p)?;
+        }
+        Ok(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolates_a_line() {
+        // f(x) = 5 + 3x through (1, 8) and (2, 11)
+        let poly = Polynomial::new(vec![
+            PointInFiniteField::new(BigNumber::from_i64(1), BigNumber::from_i64(8)),
+            PointInFiniteField::new(BigNumber::from_i64(2), BigNumber::from_i64(11)),
+        ], 2);
+        assert_eq!(poly.value_at(&BigNumber::zero()).unwrap(), BigNumber::from_i64(5));
+        assert_eq!(poly.value_at(&BigNumber::from_i64(10)).unwrap(), BigNumber::from_i64(35));
+    }
+
+    #[test]
+    fn share_string_roundtrip() {
+        let pt = PointInFiniteField::new(BigNumber::from_i64(1234567), BigNumber::from_i64(-1));
+        assert_eq!(pt.y, &field_prime() - &BigNumber::one());
+        let back = PointInFiniteField::from_share_string(&pt.to_share_string()).unwrap();
+        assert!(back.x == pt.x && back.y == pt.y);
+        assert!(!alloc::format!("{:?}", pt).contains(&pt.y.to_string()));
+        assert!(PointInFiniteField::from_share_string("abc").is_err());
+    }
+}
@@ -0,0 +1,133 @@
+// AES-256-GCM symmetric key (ts-sdk: primitives/SymmetricKey)
+use alloc::vec::Vec;
+use super::aes_gcm::{aes_gcm_decrypt, aes_gcm_encrypt, TAG_LEN};
+use crate::crypto::RandomSource;
+use crate::error::{Result, SdkError};
+use core::fmt;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Length of the random IV prefixed to every ciphertext (matches ts-sdk)
+pub const IV_LEN: usize = 32;
+
+/// Key bytes are wiped on drop, compared in constant time and never printed by `Debug`
+#[derive(Clone)]
+pub struct SymmetricKey([u8; 32]);
+
+impl Drop for SymmetricKey {
+    fn drop(&mut self) { self.0.zeroize() }
+}
+
+impl PartialEq for SymmetricKey {
+    fn eq(&self, other: &Self) -> bool { self.0.ct_eq(&other.0).into() }
+}
+impl Eq for SymmetricKey {}
+
+impl fmt::Debug for SymmetricKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SymmetricKey(<redacted>)") }
+}
+
+impl SymmetricKey {
+    pub fn from_bytes(bytes: [u8; 32]) -> Self { Self(bytes) }
+
+    /// Accept up to 32 big-endian bytes, left-padding shorter keys with zeros as ts-sdk does
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() > 32 { return Err(SdkError::InvalidArgument("symmetric key longer than 32 bytes")); }
+        let mut key = [0u8; 32];
+        key[32 - bytes.len()..].copy_from_slice(bytes);
+        Ok(Self(key))
+    }
+
+    pub fn random<R: RandomSource + ?Sized>(rng: &mut R) -> Result<Self> {
+        let mut key = [0u8; 32];
+        rng.fill_bytes(&mut key)?;
+        Ok(Self(key))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] { &self.0 }
+
+    /// Encrypt under a fresh random IV; output is `IV (32) || ciphertext || tag (16)`
+    pub fn encrypt<R: RandomSource + ?Sized>(&self, plaintext: &[u8], rng: &mut R) -> Result<Vec<u8>> {
+        let mut iv = [0u8; IV_LEN];
+        rng.fill_bytes(&mut iv)?;
+        self.encrypt_with_iv(plaintext, &iv)
+    }
+
+    /// Deterministic variant of [`SymmetricKey::encrypt`]; never reuse an IV with the same key
+    pub fn encrypt_with_iv(&self, plaintext: &[u8], iv: &[u8; IV_LEN]) -> Result<Vec<u8>> {
+        let (ct, tag) = aes_gcm_encrypt(&self.0, iv, plaintext, &[])?;
+        let mut out = Vec::with_capacity(IV_LEN + ct.len() + TAG_LEN);
+        out.extend_from_slice(iv);
+        out.extend_from_slice(&ct);
+        out.extend_from_slice(&tag);
+        Ok(out)
+    }
+
+    /// Decrypt `IV || ciphertext || tag`, failing if authentication does not pass
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        if data.len() < IV_LEN + TAG_LEN { return Err(SdkError::InvalidArgument("ciphertext too short")); }
+        let (iv, rest) = data.split_at(IV_LEN);
+        let (ct, tag) = rest.split_at(rest.len() - TAG_LEN);
+        let tag: &[u8; TAG_LEN] = tag.try_into().map_err(|_| SdkError::InvalidArgument("bad tag length"))?;
+        aes_gcm_decrypt(&self.0, iv, ct, &[], tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::hex_encode;
+
+    struct Fixed(u8);
+    impl RandomSource for Fixed {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<()> {
+            dest.fill(self.0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn wire_layout_roundtrip() {
+        let key = SymmetricKey::from_bytes([3u8; 32]);
+        let sealed = key.encrypt(b"hello bsv", &mut Fixed(9)).unwrap();
+        assert_eq!(sealed.len(), IV_LEN + 9 + TAG_LEN);
+        assert_eq!(&sealed[..IV_LEN], &[9u8; IV_LEN]);
+        assert_eq!(key.decrypt(&sealed).unwrap(), b"hello bsv");
+        assert_eq!(sealed, key.encrypt_with_iv(b"hello bsv", &[9u8; IV_LEN]).unwrap());
+    }
+
+    #[test]
+    fn known_answer_ciphertext() {
+        // Python cryptography 48.0.0 (OpenSSL) AESGCM with the same 32-byte nonce, laid out IV || ct || tag
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let iv: [u8; IV_LEN] = core::array::from_fn(|i| 0x20 + i as u8);
+        let sealed = SymmetricKey::from_bytes(key).encrypt_with_iv(b"hello from the ts-sdk wire format", &iv).unwrap();
+        assert_eq!(
+            hex_encode(&sealed),
+            "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f7fa49d24e22fb051a93c46038ee5418bf4bcc4c000d75e39d5551db1f07d95908008766582adbe1ddc2a1637a9d5aa491f"
+        );
+        assert_eq!(SymmetricKey::from_bytes(key).decrypt(&sealed).unwrap(), b"hello from the ts-sdk wire format");
+    }
+
+    #[test]
+    fn rejects_wrong_key_and_short_input() {
+        let key = SymmetricKey::from_bytes([3u8; 32]);
+        let sealed = key.encrypt(b"", &mut Fixed(1)).unwrap();
+        assert_eq!(key.decrypt(&sealed).unwrap(), b"");
+        assert!(SymmetricKey::from_bytes([4u8; 32]).decrypt(&sealed).is_err());
+        assert!(key.decrypt(&sealed[..IV_LEN + TAG_LEN - 1]).is_err());
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        assert_eq!(format!("{:?}", SymmetricKey::from_bytes([0x5a; 32])), "SymmetricKey(<redacted>)");
+    }
+
+    #[test]
+    fn short_keys_are_left_padded() {
+        let key = SymmetricKey::from_slice(&[1, 2]).unwrap();
+        assert_eq!(&key.as_bytes()[30..], &[1, 2]);
+        assert!(key.as_bytes()[..30].iter().all(|b| *b == 0));
+        assert!(SymmetricKey::from_slice(&[0u8; 33]).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+// Standard output script recognition
+use alloc::vec::Vec;
+use super::{Chunk, Opcode, Script, OP_0, OP_CHECKSIG, OP_DUP, OP_EQUALVERIFY, OP_HASH160, OP_RETURN};
+
+/// What a locking script pays to, with the fields an indexer needs to attribute it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptType {
+    /// `OP_DUP OP_HASH160 <20> OP_EQUALVERIFY OP_CHECKSIG`
+    P2pkh { pubkey_hash: [u8; 20] },
+    /// `<pubkey> OP_CHECKSIG`, with the key as written (33 or 65 bytes)
+    P2pk { pubkey: Vec<u8> },
+    /// `OP_m <pubkey>... OP_n OP_CHECKMULTISIG`
+    Multisig { required: usize, pubkeys: Vec<Vec<u8>> },
+    /// `[OP_FALSE] OP_RETURN ...`; `raw` is every byte after `OP_RETURN`, and `data` its
+    /// pushes when it parses as nothing but pushes (empty otherwise)
+    OpReturn { data: Vec<Vec<u8>>, raw: Vec<u8> },
+    /// Data fields dropped before (or after) an optional `<pubkey> OP_CHECKSIG` lock
+    PushDrop { fields: Vec<Vec<u8>>, pubkey: Option<Vec<u8>> },
+    NonStandard,
+}
+
+/// Classify a locking script; anything unparsable or unrecognised is `NonStandard`
+pub fn classify(script: &Script) -> ScriptType {
+    let b = script.as_bytes();
+    if b.len() == 25 && b[..3] == [OP_DUP, OP_HASH160, 20] && b[23..] == [OP_EQUALVERIFY, OP_CHECKSIG] {
+        let mut pubkey_hash = [0u8; 20];
+        pubkey_hash.copy_from_slice(&b[3..23]);
+        return ScriptType::P2pkh { pubkey_hash };
+    }
+    if let Some(found) = op_return(b) { return found; }
+    let Ok(chunks) = script.parse() else { return ScriptType::NonStandard };
+    p2pk(&chunks)
+        .or_else(|| multisig(&chunks))
+        .or_else(|| push_drop(&chunks))
+        .unwrap_or(ScriptType::NonStandard)
+}
+
+// Data carried by a push, including the small-integer opcodes
+fn push_value(chunk: &Chunk) -> Option<Vec<u8>> {
+    match chunk {
+        Chunk::Push(d) => Some(d.clone()),
+        Chunk::Op(Opcode::OP_0) => Some(Vec::new()),
+        Chunk::Op(Opcode::OP_1NEGATE) => Some(alloc::vec![0x81]),
+        Chunk::Op(op) => op.small_int().map(|n| alloc::vec![n]),
+    }
+}
+
+fn is_pubkey(data: &[u8]) -> bool {
+    matches!((data.len(), data.first()), (33, Some(0x02 | 0x03)) | (65, Some(0x04)))
+}
+
+// Checked on the raw bytes: nothing after OP_RETURN is executed, so it need not parse
+fn op_return(b: &[u8]) -> Option<ScriptType> {
+    let raw = match b {
+        [OP_RETURN, rest @ ..] | [OP_0, OP_RETURN, rest @ ..] => rest.to_vec(),
+        _ => return None,
+    };
+    let data = Script(raw.clone()).parse().ok()
+        .and_then(|chunks| chunks.iter().map(push_value).collect::<Option<Vec<_>>>())
+        .unwrap_or_default();
+    Some(ScriptType::OpReturn { data, raw })
+}
+
+fn p2pk(chunks: &[Chunk]) -> Option<ScriptType> {
+    match chunks {
+        [Chunk::Push(pubkey), Chunk::Op(Opcode::OP_CHECKSIG)] if is_pubkey(pubkey) => Some(ScriptType::P2pk { pubkey: pubkey.clone() }),
+        _ => None,
+    }
+}
+
+fn multisig(chunks: &[Chunk]) -> Option<ScriptType> {
+    let [Chunk::Op(m), keys @ .., Chunk::Op(n), Chunk::Op(Opcode::OP_CHECKMULTISIG)] = chunks else { return None };
+    let (required, total) = (m.small_int()? as usize, n.small_int()? as usize);
+    if required > total || keys.len() != total { return None; }
+    let pubkeys = keys.iter()
+        .map(|c| match c { Chunk::Push(k) if is_pubkey(k) => Some(k.clone()), _ => None })
+        .collect::<Option<Vec<_>>>()?;
+    Some(ScriptType::Multisig { required, pubkeys })
+}
+
+// Fields, then OP_2DROP/OP_DROP removing exactly those fields, with an optional
+// `<pubkey> OP_CHECKSIG` lock either before the fields or after the drops
+fn push_drop(chunks: &[Chunk]) -> Option<ScriptType> {
+    let (pubkey, body) = match chunks {
+        [Chunk::Push(k), Chunk::Op(Opcode::OP_CHECKSIG), body @ ..] if is_pubkey(k) => (Some(k.clone()), body),
+        [body @ .., Chunk::Push(k), Chunk::Op(Opcode::OP_CHECKSIG)] if is_pubkey(k) => (Some(k.clone()), body),
+        _ => (None, chunks),
+    };
+    let field_count = body.iter().take_while(|c| push_value(c).is_some()).count();
+    let mut dropped = 0;
+    for c in &body[field_count..] {
+        dropped += match c {
+            Chunk::Op(Opcode::OP_2DROP) => 2,
+            Chunk::Op(Opcode::OP_DROP) => 1,
+            _ => return None,
+        };
+    }
+    if field_count == 0 || dropped != field_count { return None; }
+    let fields = body[..field_count].iter().filter_map(push_value).collect();
+    Some(ScriptType::PushDrop { fields, pubkey })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::script::templates::{P2pkhTemplate, PushDropTemplate};
+    use crate::script::{OP_2DROP, OP_CHECKMULTISIG, OP_DROP, OP_PUSHDATA1};
+
+    fn key(prefix: u8) -> Vec<u8> {
+        let mut k = alloc::vec![prefix; 33];
+        k[0] = 0x02;
+        k
+    }
+
+    #[test]
+    fn recognises_key_scripts() {
+        assert_eq!(classify(&P2pkhTemplate::locking_script([7; 20])), ScriptType::P2pkh { pubkey_hash: [7; 20] });
+        let p2pk = Script::new().push_data(&key(1)).push_opcode(OP_CHECKSIG);
+        assert_eq!(classify(&p2pk), ScriptType::P2pk { pubkey: key(1) });
+        let multi = Script::new().push_small_int(2).push_data(&key(1)).push_data(&key(2)).push_data(&key(3))
+            .push_small_int(3).push_opcode(OP_CHECKMULTISIG);
+        assert_eq!(classify(&multi), ScriptType::Multisig { required: 2, pubkeys: alloc::vec![key(1), key(2), key(3)] });
+        let bad_multi = Script::new().push_small_int(3).push_data(&key(1)).push_small_int(1).push_opcode(OP_CHECKMULTISIG);
+        assert_eq!(classify(&bad_multi), ScriptType::NonStandard);
+    }
+
+    #[test]
+    fn recognises_data_scripts() {
+        let safe = Script::new().push_opcode(OP_0).push_opcode(OP_RETURN).push_data(b"hello").push_data(b"");
+        let raw = safe.as_bytes()[2..].to_vec();
+        assert_eq!(classify(&safe), ScriptType::OpReturn { data: alloc::vec![b"hello".to_vec(), Vec::new()], raw });
+        assert_eq!(classify(&Script::new().push_opcode(OP_RETURN)), ScriptType::OpReturn { data: Vec::new(), raw: Vec::new() });
+        assert_eq!(classify(&PushDropTemplate::locking_script(b"token")), ScriptType::PushDrop { fields: alloc::vec![b"token".to_vec()], pubkey: None });
+        let locked = Script::new().push_data(&key(9)).push_opcode(OP_CHECKSIG)
+            .push_data(b"a").push_data(b"b").push_small_int(3).push_opcode(OP_2DROP).push_opcode(OP_DROP);
+        assert_eq!(classify(&locked), ScriptType::PushDrop { fields: alloc::vec![b"a".to_vec(), b"b".to_vec(), alloc::vec![3]], pubkey: Some(key(9)) });
+        let unbalanced = Script::new().push_data(b"a").push_data(b"b").push_opcode(OP_DROP);
+        assert_eq!(classify(&unbalanced), ScriptType::NonStandard);
+    }
+
+    #[test]
+    fn op_return_keeps_unparsable_remainder() {
+        // a non-push opcode after the data
+        let tail = Script(alloc::vec![OP_RETURN, 0x01, 0xaa, OP_DUP]);
+        assert_eq!(classify(&tail), ScriptType::OpReturn { data: Vec::new(), raw: alloc::vec![0x01, 0xaa, OP_DUP] });
+        // a push running past the end of the script
+        let truncated = Script(alloc::vec![OP_0, OP_RETURN, OP_PUSHDATA1, 10, 0xbb]);
+        assert_eq!(classify(&truncated), ScriptType::OpReturn { data: Vec::new(), raw: alloc::vec![OP_PUSHDATA1, 10, 0xbb] });
+    }
+
+    #[test]
+    fn malformed_is_non_standard() {
+        assert_eq!(classify(&Script(alloc::vec![0x05, 0x01])), ScriptType::NonStandard);
+        assert_eq!(classify(&Script::new()), ScriptType::NonStandard);
+        assert_eq!(classify(&Script::new().push_opcode(OP_DUP)), ScriptType::NonStandard);
+    }
+}
@@ -1,49 +1,187 @@
 // Script module placeholders
+use alloc::{string::{String, ToString}, vec::Vec};
+use crate::error::{Result, SdkError};
+use crate::util::{hex_decode, hex_encode};
+use core::fmt;
+pub mod classify;
+pub mod opcode;
+pub mod script_num;
+pub mod script_template;
+pub mod spend;
 pub mod templates; // mirror ts-sdk script/templates
 
-// Common opcode constants (subset)
+pub use classify::{classify, ScriptType};
+pub use opcode::Opcode;
+pub use script_num::ScriptNum;
+pub use script_template::{ScriptTemplate, UnlockingScriptTemplate};
+pub use spend::Spend;
+
+// Opcode constants
 pub const OP_0: u8 = 0x00;
-pub const OP_DUP: u8 = 0x76;
-pub const OP_HASH160: u8 = 0xA9;
-pub const OP_EQUALVERIFY: u8 = 0x88;
-pub const OP_CHECKSIG: u8 = 0xAC;
-pub const OP_DROP: u8 = 0x75;
-pub const OP_EQUAL: u8 = 0x87;
-pub const OP_SIZE: u8 = 0x82;
-pub const OP_SHA256: u8 = 0xA8;
 pub const OP_PUSHDATA1: u8 = 0x4C;
 pub const OP_PUSHDATA2: u8 = 0x4D;
 pub const OP_PUSHDATA4: u8 = 0x4E;
+pub const OP_1NEGATE: u8 = 0x4F;
+pub const OP_RESERVED: u8 = 0x50;
+pub const OP_1: u8 = 0x51;
+pub const OP_2: u8 = 0x52;
+pub const OP_3: u8 = 0x53;
+pub const OP_4: u8 = 0x54;
+pub const OP_5: u8 = 0x55;
+pub const OP_6: u8 = 0x56;
+pub const OP_7: u8 = 0x57;
+pub const OP_8: u8 = 0x58;
+pub const OP_9: u8 = 0x59;
+pub const OP_10: u8 = 0x5A;
+pub const OP_11: u8 = 0x5B;
+pub const OP_12: u8 = 0x5C;
+pub const OP_13: u8 = 0x5D;
+pub const OP_14: u8 = 0x5E;
+pub const OP_15: u8 = 0x5F;
+pub const OP_16: u8 = 0x60;
+pub const OP_NOP: u8 = 0x61;
+pub const OP_VER: u8 = 0x62;
+pub const OP_IF: u8 = 0x63;
+pub const OP_NOTIF: u8 = 0x64;
+pub const OP_VERIF: u8 = 0x65;
+pub const OP_VERNOTIF: u8 = 0x66;
+pub const OP_ELSE: u8 = 0x67;
+pub const OP_ENDIF: u8 = 0x68;
+pub const OP_VERIFY: u8 = 0x69;
+pub const OP_RETURN: u8 = 0x6A;
+pub const OP_TOALTSTACK: u8 = 0x6B;
+pub const OP_FROMALTSTACK: u8 = 0x6C;
+pub const OP_2DROP: u8 = 0x6D;
+pub const OP_2DUP: u8 = 0x6E;
+pub const OP_3DUP: u8 = 0x6F;
+pub const OP_2OVER: u8 = 0x70;
+pub const OP_2ROT: u8 = 0x71;
+pub const OP_2SWAP: u8 = 0x72;
+pub const OP_IFDUP: u8 = 0x73;
+pub const OP_DEPTH: u8 = 0x74;
+pub const OP_DROP: u8 = 0x75;
+pub const OP_DUP: u8 = 0x76;
+pub const OP_NIP: u8 = 0x77;
+pub const OP_OVER: u8 = 0x78;
+pub const OP_PICK: u8 = 0x79;
+pub const OP_ROLL: u8 = 0x7A;
+pub const OP_ROT: u8 = 0x7B;
+pub const OP_SWAP: u8 = 0x7C;
+pub const OP_TUCK: u8 = 0x7D;
+pub const OP_CAT: u8 = 0x7E;
+pub const OP_SPLIT: u8 = 0x7F;
+pub const OP_NUM2BIN: u8 = 0x80;
+pub const OP_BIN2NUM: u8 = 0x81;
+pub const OP_SIZE: u8 = 0x82;
+pub const OP_INVERT: u8 = 0x83;
+pub const OP_AND: u8 = 0x84;
+pub const OP_OR: u8 = 0x85;
+pub const OP_XOR: u8 = 0x86;
+pub const OP_EQUAL: u8 = 0x87;
+pub const OP_EQUALVERIFY: u8 = 0x88;
+pub const OP_RESERVED1: u8 = 0x89;
+pub const OP_RESERVED2: u8 = 0x8A;
+pub const OP_1ADD: u8 = 0x8B;
+pub const OP_1SUB: u8 = 0x8C;
+pub const OP_2MUL: u8 = 0x8D;
+pub const OP_2DIV: u8 = 0x8E;
+pub const OP_NEGATE: u8 = 0x8F;
+pub const OP_ABS: u8 = 0x90;
+pub const OP_NOT: u8 = 0x91;
+pub const OP_0NOTEQUAL: u8 = 0x92;
+pub const OP_ADD: u8 = 0x93;
+pub const OP_SUB: u8 = 0x94;
+pub const OP_MUL: u8 = 0x95;
+pub const OP_DIV: u8 = 0x96;
+pub const OP_MOD: u8 = 0x97;
+pub const OP_LSHIFT: u8 = 0x98;
+pub const OP_RSHIFT: u8 = 0x99;
+pub const OP_BOOLAND: u8 = 0x9A;
+pub const OP_BOOLOR: u8 = 0x9B;
+pub const OP_NUMEQUAL: u8 = 0x9C;
+pub const OP_NUMEQUALVERIFY: u8 = 0x9D;
+pub const OP_NUMNOTEQUAL: u8 = 0x9E;
+pub const OP_LESSTHAN: u8 = 0x9F;
+pub const OP_GREATERTHAN: u8 = 0xA0;
+pub const OP_LESSTHANOREQUAL: u8 = 0xA1;
+pub const OP_GREATERTHANOREQUAL: u8 = 0xA2;
+pub const OP_MIN: u8 = 0xA3;
+pub const OP_MAX: u8 = 0xA4;
+pub const OP_WITHIN: u8 = 0xA5;
+pub const OP_RIPEMD160: u8 = 0xA6;
+pub const OP_SHA1: u8 = 0xA7;
+pub const OP_SHA256: u8 = 0xA8;
+pub const OP_HASH160: u8 = 0xA9;
+pub const OP_HASH256: u8 = 0xAA;
+pub const OP_CODESEPARATOR: u8 = 0xAB;
+pub const OP_CHECKSIG: u8 = 0xAC;
+pub const OP_CHECKSIGVERIFY: u8 = 0xAD;
+pub const OP_CHECKMULTISIG: u8 = 0xAE;
+pub const OP_CHECKMULTISIGVERIFY: u8 = 0xAF;
+pub const OP_NOP1: u8 = 0xB0;
+pub const OP_NOP2: u8 = 0xB1;
+pub const OP_NOP3: u8 = 0xB2;
+pub const OP_NOP4: u8 = 0xB3;
+pub const OP_NOP5: u8 = 0xB4;
+pub const OP_NOP6: u8 = 0xB5;
+pub const OP_NOP7: u8 = 0xB6;
+pub const OP_NOP8: u8 = 0xB7;
+pub const OP_NOP9: u8 = 0xB8;
+pub const OP_NOP10: u8 = 0xB9;
+pub const OP_INVALIDOPCODE: u8 = 0xFF;
+
+// Aliases
+pub const OP_FALSE: u8 = OP_0;
+pub const OP_TRUE: u8 = OP_1;
+pub const OP_CHECKLOCKTIMEVERIFY: u8 = OP_NOP2;
+pub const OP_CHECKSEQUENCEVERIFY: u8 = OP_NOP3;
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Script(pub Vec<u8>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Chunk {
-    Op(u8),
+    Op(Opcode),
     Push(Vec<u8>),
 }
 
+/// ASM token: opcode name, or hex for pushed data. `OP_0` and `OP_1NEGATE` print as `0` and `-1`.
+impl fmt::Display for Chunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Chunk::Op(Opcode::OP_0) => f.write_str("0"),
+            Chunk::Op(Opcode::OP_1NEGATE) => f.write_str("-1"),
+            Chunk::Op(op) => f.write_str(op.name()),
+            Chunk::Push(data) if data.is_empty() => f.write_str("0"),
+            Chunk::Push(data) => f.write_str(&hex_encode(data)),
+        }
+    }
+}
+
 impl Script {
     pub fn new() -> Self { Self(Vec::new()) }
     pub fn into_bytes(self) -> Vec<u8> { self.0 }
     pub fn as_bytes(&self) -> &[u8] { &self.0 }
 
-    pub fn push_opcode(mut self, op: u8) -> Self {
-        self.0.push(op);
+    pub fn push_opcode(mut self, op: impl Into<u8>) -> Self {
+        self.0.push(op.into());
         self
     }
 
-    pub fn push_small_int(mut self, n: u8) -> Self {
-        // OP_1 .. OP_16 are 0x51..0x60; OP_0 is 0x00
+    pub fn push_small_int(self, n: u8) -> Self { self.push_int(n as i64) }
+
+    /// Push a number the way relay policy expects: OP_0, OP_1NEGATE or OP_1..OP_16 when
+    /// possible, otherwise its minimal [`ScriptNum`] encoding
+    pub fn push_int(self, n: i64) -> Self {
         match n {
-            0 => self.0.push(OP_0),
-            1..=16 => self.0.push(0x50 + n),
-            _ => self = self.push_data(&[n]),
+            0 => self.push_opcode(OP_0),
+            -1 => self.push_opcode(OP_1NEGATE),
+            1..=16 => self.push_opcode(OP_1 - 1 + n as u8),
+            _ => self.push_data(&ScriptNum(n).encode()),
         }
-        self
     }
 
+    /// Push `data` as bytes with the shortest length prefix, never substituting an opcode
     pub fn push_data(mut self, data: &[u8]) -> Self {
         let len = data.len();
         if len < 0x4c { // direct length push
@@ -62,47 +200,177 @@ impl Script {
         self
     }
 
+    /// Minimal push, as relay policy wants: empty data, single bytes 1..=16 and 0x81 use
+    /// their dedicated opcodes; anything else is [`push_data`](Self::push_data)
+    pub fn push_data_minimal(self, data: &[u8]) -> Self {
+        match *data {
+            [] => self.push_opcode(OP_0),
+            [n @ 1..=16] => self.push_opcode(OP_1 - 1 + n),
+            [0x81] => self.push_opcode(OP_1NEGATE),
+            _ => self.push_data(data),
+        }
+    }
+
     // Parse the script into chunks of opcodes and pushed data
-    pub fn parse(&self) -> Result<Vec<Chunk>, String> {
-        let mut i = 0usize;
-        let b = &self.0;
-        let mut chunks = Vec::new();
-        while i < b.len() {
-            let opcode = b[i];
-            i += 1;
-            match opcode {
-                0x01..=0x4b => {
-                    let len = (opcode as usize);
-                    if i + len > b.len() { return Err("push length exceeds script size".into()); }
-                    chunks.push(Chunk::Push(b[i..i+len].to_vec()));
-                    i += len;
-                }
-                OP_PUSHDATA1 => {
-                    if i + 1 > b.len() { return Err("PUSHDATA1 missing length".into()); }
-                    let len = b[i] as usize; i += 1;
-                    if i + len > b.len() { return Err("PUSHDATA1 length exceeds script size".into()); }
-                    chunks.push(Chunk::Push(b[i..i+len].to_vec()));
-                    i += len;
-                }
-                OP_PUSHDATA2 => {
-                    if i + 2 > b.len() { return Err("PUSHDATA2 missing length".into()); }
-                    let len = u16::from_le_bytes([b[i], b[i+1]]) as usize; i += 2;
-                    if i + len > b.len() { return Err("PUSHDATA2 length exceeds script size".into()); }
-                    chunks.push(Chunk::Push(b[i..i+len].to_vec()));
-                    i += len;
-                }
-                OP_PUSHDATA4 => {
-                    if i + 4 > b.len() { return Err("PUSHDATA4 missing length".into()); }
-                    let len = u32::from_le_bytes([b[i], b[i+1], b[i+2], b[i+3]]) as usize; i += 4;
-                    if i + len > b.len() { return Err("PUSHDATA4 length exceeds script size".into()); }
-                    chunks.push(Chunk::Push(b[i..i+len].to_vec()));
-                    i += len;
-                }
-                op => {
-                    chunks.push(Chunk::Op(op));
-                }
+    pub fn parse(&self) -> core::result::Result<Vec<Chunk>, String> {
+        self.instructions()
+            .map(|ins| ins.map(|i| if i.is_push_data() { Chunk::Push(i.data.to_vec()) } else { Chunk::Op(i.opcode.into()) }).map_err(String::from))
+            .collect()
+    }
+
+    /// True when the script parses and every push uses the shortest possible opcode
+    pub fn is_minimally_encoded(&self) -> bool {
+        self.instructions().all(|ins| matches!(ins, Ok(i) if i.opcode > OP_PUSHDATA4 || is_minimal_push(i.opcode, i.data)))
+    }
+
+    /// Space-separated ASM in the ts-sdk format, which matches bitcoind's except that
+    /// bitcoind prints small integers and pushes of up to four bytes in decimal
+    /// (see [`to_bitcoind_asm`](Self::to_bitcoind_asm))
+    pub fn to_asm(&self) -> Result<String> {
+        let chunks = self.parse().map_err(|_| SdkError::ParseError("asm: malformed script"))?;
+        let tokens: Vec<String> = chunks.iter().map(|c| alloc::format!("{}", c)).collect();
+        Ok(tokens.join(" "))
+    }
+
+    /// ASM as bitcoind's `decodescript` prints it: pushes of up to four bytes and
+    /// `OP_1NEGATE`..`OP_16` as decimal numbers, longer pushes as hex
+    pub fn to_bitcoind_asm(&self) -> Result<String> {
+        let chunks = self.parse().map_err(|_| SdkError::ParseError("asm: malformed script"))?;
+        let tokens: Vec<String> = chunks.iter().map(|c| match c {
+            Chunk::Push(data) => match ScriptNum::decode(data, false, 4) {
+                Ok(n) => n.0.to_string(),
+                Err(_) => hex_encode(data),
+            },
+            Chunk::Op(op) => op.small_int().map_or_else(|| c.to_string(), |n| n.to_string()),
+        }).collect();
+        Ok(tokens.join(" "))
+    }
+
+    /// Parse ASM. Bare tokens are hex data (odd lengths get a leading zero), `0` and `-1` are
+    /// `OP_0` and `OP_1NEGATE`, and `OP_PUSHDATAn <len> <hex>` keeps the explicit push opcode.
+    /// `OP_PUSHBYTES_n` must be followed by exactly `n` bytes of hex.
+    pub fn from_asm(asm: &str) -> Result<Self> { Self::parse_asm(asm, false) }
+
+    /// Parse ASM as bitcoind's `decodescript` prints it: decimal tokens of up to four bytes
+    /// are numbers, pushed with [`push_int`](Self::push_int), and longer pushes are hex. A
+    /// non-minimal push therefore comes back minimal, and a ten-digit token that fits in four
+    /// bytes is read as a number even where bitcoind meant five bytes of hex.
+    pub fn from_bitcoind_asm(asm: &str) -> Result<Self> { Self::parse_asm(asm, true) }
+
+    fn parse_asm(asm: &str, decimal: bool) -> Result<Self> {
+        let mut script = Script::new();
+        let mut tokens = asm.split_whitespace();
+        while let Some(token) = tokens.next() {
+            if let Some(n) = asm_number(token).filter(|_| decimal) {
+                script = script.push_int(n);
+                continue;
             }
+            script = match token {
+                "0" => script.push_opcode(OP_0),
+                "-1" => script.push_opcode(OP_1NEGATE),
+                _ if token.starts_with("OP_") => {
+                    let op = Opcode::from_name(token).ok_or(SdkError::ParseError("asm: unknown opcode"))?;
+                    match op {
+                        Opcode::OP_PUSHDATA1 | Opcode::OP_PUSHDATA2 | Opcode::OP_PUSHDATA4 => {
+                            let (Some(len), Some(hex)) = (tokens.next(), tokens.next()) else {
+                                return Err(SdkError::ParseError("asm: OP_PUSHDATA missing length or data"));
+                            };
+                            let len: usize = len.parse().map_err(|_| SdkError::ParseError("asm: OP_PUSHDATA length is not a decimal number"))?;
+                            let data = asm_hex(hex)?;
+                            if data.len() != len { return Err(SdkError::ParseError("asm: OP_PUSHDATA length does not match its data")); }
+                            script.push_data_with(op, &data)?
+                        }
+                        // a bare length byte would swallow whatever follows, so the data must come with it
+                        _ if op.to_u8() < OP_PUSHDATA1 && op != Opcode::OP_0 => {
+                            let data = asm_hex(tokens.next().ok_or(SdkError::ParseError("asm: OP_PUSHBYTES missing data"))?)?;
+                            if data.len() != op.to_u8() as usize { return Err(SdkError::ParseError("asm: OP_PUSHBYTES length does not match its data")); }
+                            script.push_data(&data)
+                        }
+                        _ => script.push_opcode(op),
+                    }
+                }
+                _ => script.push_data(&asm_hex(token)?),
+            };
+        }
+        Ok(script)
+    }
+
+    // Push with a caller-chosen PUSHDATA opcode, failing when the data does not fit its length field
+    fn push_data_with(mut self, op: Opcode, data: &[u8]) -> Result<Self> {
+        let len = data.len();
+        self.0.push(op.into());
+        match op {
+            Opcode::OP_PUSHDATA1 if len <= 0xff => self.0.push(len as u8),
+            Opcode::OP_PUSHDATA2 if len <= 0xffff => self.0.extend_from_slice(&(len as u16).to_le_bytes()),
+            Opcode::OP_PUSHDATA4 if len <= u32::MAX as usize => self.0.extend_from_slice(&(len as u32).to_le_bytes()),
+            _ => return Err(SdkError::ParseError("asm: data too long for OP_PUSHDATA")),
         }
-        Ok(chunks)
+        self.0.extend_from_slice(data);
+        Ok(self)
+    }
+
+    /// Walk the script one opcode at a time without copying push data
+    pub(crate) fn instructions(&self) -> Instructions<'_> { Instructions { bytes: &self.0, pos: 0 } }
+}
+
+/// Whether `op` is the shortest opcode able to push `data`
+pub(crate) fn is_minimal_push(op: u8, data: &[u8]) -> bool {
+    match data {
+        [] => op == OP_0,
+        [1..=16] | [0x81] => false,
+        _ if data.len() <= 0x4b => op as usize == data.len(),
+        _ if data.len() <= 0xff => op == OP_PUSHDATA1,
+        _ if data.len() <= 0xffff => op == OP_PUSHDATA2,
+        _ => true,
+    }
+}
+
+// A bitcoind ASM number: decimal, within the four-byte script number range
+fn asm_number(token: &str) -> Option<i64> {
+    let digits = token.strip_prefix('-').unwrap_or(token);
+    if digits.is_empty() || digits.len() > 10 || !digits.bytes().all(|b| b.is_ascii_digit()) { return None; }
+    token.parse::<i64>().ok().filter(|n| n.abs() <= i32::MAX as i64)
+}
+
+fn asm_hex(token: &str) -> Result<Vec<u8>> {
+    if !token.len().is_multiple_of(2) { hex_decode(&alloc::format!("0{}", token)) } else { hex_decode(token) }
+}
+
+/// One decoded opcode; `data` is the pushed bytes for 0x01..=OP_PUSHDATA4 and empty otherwise
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Instruction<'a> { pub opcode: u8, pub data: &'a [u8], pub end: usize }
+
+impl Instruction<'_> {
+    pub fn is_push_data(&self) -> bool { (0x01..=OP_PUSHDATA4).contains(&self.opcode) }
+}
+
+pub(crate) struct Instructions<'a> { bytes: &'a [u8], pos: usize }
+
+impl<'a> Iterator for Instructions<'a> {
+    type Item = core::result::Result<Instruction<'a>, &'static str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let b = self.bytes;
+        let opcode = *b.get(self.pos)?;
+        let mut i = self.pos + 1;
+        let len = match opcode {
+            0x01..=0x4b => opcode as usize,
+            OP_PUSHDATA1 | OP_PUSHDATA2 | OP_PUSHDATA4 => {
+                let (width, missing) = match opcode {
+                    OP_PUSHDATA1 => (1, "PUSHDATA1 missing length"),
+                    OP_PUSHDATA2 => (2, "PUSHDATA2 missing length"),
+                    _ => (4, "PUSHDATA4 missing length"),
+                };
+                if i + width > b.len() { self.pos = b.len(); return Some(Err(missing)); }
+                let mut le = [0u8; 4];
+                le[..width].copy_from_slice(&b[i..i + width]);
+                i += width;
+                u32::from_le_bytes(le) as usize
+            }
+            _ => 0,
+        };
+        if len > b.len() - i { self.pos = b.len(); return Some(Err("push length exceeds script size")); }
+        self.pos = i + len;
+        Some(Ok(Instruction { opcode, data: &b[i..i + len], end: self.pos }))
     }
 }
@@ -0,0 +1,128 @@
+// Script numbers: little-endian sign-magnitude integers as used by numeric opcodes
+use alloc::vec::Vec;
+use crate::error::{Result, SdkError};
+
+/// Longest numeric operand the consensus rules accepted before Genesis
+pub const DEFAULT_MAX_LENGTH: usize = 4;
+
+/// A script number that fits in an `i64`; wider operands go through [`BigNumber`](crate::primitives::BigNumber)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScriptNum(pub i64);
+
+impl ScriptNum {
+    /// Minimal encoding; zero is the empty vector
+    pub fn encode(self) -> Vec<u8> {
+        let magnitude = self.0.unsigned_abs().to_le_bytes();
+        let len = magnitude.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        encode_magnitude(magnitude[..len].to_vec(), self.0 < 0)
+    }
+
+    /// Decode at most `max_len` bytes (and never more than 8). With `require_minimal`,
+    /// encodings with a redundant trailing byte, including negative zero, are rejected.
+    pub fn decode(bytes: &[u8], require_minimal: bool, max_len: usize) -> Result<Self> {
+        check_encoding(bytes, require_minimal, max_len.min(8)).map_err(SdkError::ScriptError)?;
+        let (magnitude, negative) = decode_magnitude(bytes);
+        let magnitude = magnitude.iter().rev().fold(0u64, |acc, &b| (acc << 8) | b as u64) as i64;
+        Ok(Self(if negative { -magnitude } else { magnitude }))
+    }
+
+    /// [`decode`](Self::decode) with the usual operand rules: minimal, at most [`DEFAULT_MAX_LENGTH`] bytes
+    pub fn decode_minimal(bytes: &[u8]) -> Result<Self> { Self::decode(bytes, true, DEFAULT_MAX_LENGTH) }
+}
+
+impl From<i64> for ScriptNum {
+    fn from(n: i64) -> Self { Self(n) }
+}
+
+/// True when `bytes` has no redundant trailing byte
+pub fn is_minimal(bytes: &[u8]) -> bool {
+    match bytes.split_last() {
+        Some((&last, rest)) if last & 0x7f == 0 => rest.last().is_some_and(|b| b & 0x80 != 0),
+        _ => true,
+    }
+}
+
+/// Length and minimality rules shared by every script number decoder
+pub(crate) fn check_encoding(bytes: &[u8], require_minimal: bool, max_len: usize) -> core::result::Result<(), &'static str> {
+    if bytes.len() > max_len { return Err("script number overflow"); }
+    if require_minimal && !is_minimal(bytes) { return Err("non-minimally encoded script number"); }
+    Ok(())
+}
+
+/// Sign-magnitude encoding of a little-endian magnitude with no trailing zero bytes
+pub(crate) fn encode_magnitude(mut magnitude: Vec<u8>, negative: bool) -> Vec<u8> {
+    if let Some(last) = magnitude.last_mut() {
+        if *last & 0x80 != 0 {
+            magnitude.push(if negative { 0x80 } else { 0x00 });
+        } else if negative {
+            *last |= 0x80;
+        }
+    }
+    magnitude
+}
+
+/// Little-endian magnitude and sign of an encoded number
+pub(crate) fn decode_magnitude(bytes: &[u8]) -> (Vec<u8>, bool) {
+    let mut magnitude = bytes.to_vec();
+    let Some(last) = magnitude.last_mut() else { return (magnitude, false) };
+    let negative = *last & 0x80 != 0;
+    *last &= 0x7f;
+    (magnitude, negative)
+}
+
+/// Drop redundant trailing bytes of a number of any length, keeping its value and sign
+pub fn minimally_encode(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    let Some(last) = out.last_mut() else { return out };
+    let sign = *last & 0x80;
+    *last &= 0x7f;
+    while out.last() == Some(&0) { out.pop(); }
+    match out.last_mut() {
+        None => {}
+        Some(top) if *top & 0x80 != 0 => out.push(sign),
+        Some(top) => *top |= sign,
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn encode_known_values() {
+        let cases: &[(i64, &[u8])] = &[
+            (0, &[]), (1, &[0x01]), (-1, &[0x81]), (127, &[0x7f]), (128, &[0x80, 0x00]), (-128, &[0x80, 0x80]),
+            (255, &[0xff, 0x00]), (256, &[0x00, 0x01]), (-256, &[0x00, 0x81]), (32767, &[0xff, 0x7f]),
+            (i64::MAX, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]),
+        ];
+        for &(n, bytes) in cases {
+            assert_eq!(ScriptNum(n).encode(), bytes, "{}", n);
+            assert_eq!(ScriptNum::decode(bytes, true, 8).unwrap(), ScriptNum(n));
+        }
+        assert_eq!(ScriptNum(i64::MIN).encode().len(), 9);
+    }
+
+    #[test]
+    fn decode_limits_and_minimality() {
+        assert_eq!(ScriptNum::decode(&[0x01, 0x00], false, 4).unwrap(), ScriptNum(1));
+        assert!(ScriptNum::decode(&[0x01, 0x00], true, 4).is_err());
+        assert!(ScriptNum::decode(&[0x80], true, 4).is_err());
+        assert!(ScriptNum::decode(&[0x00], true, 4).is_err());
+        assert!(ScriptNum::decode(&[0x80, 0x80], true, 4).is_ok());
+        assert!(matches!(ScriptNum::decode(&[1, 2, 3, 4, 5], false, 4), Err(SdkError::ScriptError(_))));
+        assert_eq!(ScriptNum::decode_minimal(&[0xff, 0xff, 0xff, 0x7f]).unwrap(), ScriptNum(i32::MAX as i64));
+        assert!(ScriptNum::decode_minimal(&[0, 0, 0, 0x80, 0]).is_err());
+        assert!(ScriptNum::decode_minimal(&[0x01, 0x00]).is_err());
+        assert!(ScriptNum::decode(&[1; 9], false, 100).is_err());
+    }
+
+    #[test]
+    fn minimal_encoding() {
+        assert_eq!(minimally_encode(&[5, 0, 0, 0x80]), vec![0x85]);
+        assert_eq!(minimally_encode(&[0x80, 0x00]), vec![0x80, 0x00]);
+        assert_eq!(minimally_encode(&[0, 0x80]), Vec::<u8>::new());
+        assert!(is_minimal(&[]) && is_minimal(&[0xff, 0x80]) && !is_minimal(&[0x7f, 0x80]));
+    }
+}
@@ -0,0 +1,22 @@
+// Template traits (ts-sdk: script/ScriptTemplate)
+use super::Script;
+use crate::error::Result;
+use crate::transaction::Transaction;
+
+/// Produces the unlocking script for one input once the rest of the transaction is final
+pub trait UnlockingScriptTemplate {
+    fn sign(&self, tx: &Transaction, input_index: usize) -> Result<Script>;
+
+    /// Upper bound on the unlocking script's length in bytes, for sizing fees before signing
+    fn estimate_length(&self, tx: &Transaction, input_index: usize) -> usize;
+}
+
+/// A kind of output: `lock` builds its locking script, `unlock` captures what a spend of it needs
+pub trait ScriptTemplate {
+    type LockParams;
+    type UnlockParams;
+    type Unlocker: UnlockingScriptTemplate;
+
+    fn lock(&self, params: Self::LockParams) -> Script;
+    fn unlock(&self, params: Self::UnlockParams) -> Self::Unlocker;
+}
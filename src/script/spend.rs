@@ -0,0 +1,549 @@
+// Script interpreter (ts-sdk: script/Spend) with post-Genesis BSV rules
+use alloc::{vec, vec::Vec};
+use super::*;
+use super::script_num::minimally_encode;
+use crate::crypto::{hash160, ripemd160, sha1, sha256, sha256d, verify_hash, Hash160, PublicKey, Ripemd160, Sha1, Sha256, Signature};
+use crate::error::{Result, SdkError};
+use crate::primitives::BigNumber;
+use crate::transaction::sighash::{sighash, SigHashType, SIGHASH_FORKID};
+use crate::transaction::Transaction;
+
+/// Largest numeric operand, in bytes, after Genesis
+pub const MAX_SCRIPT_NUM_LENGTH: usize = 750_000;
+/// Default cap on the combined size of the main and alt stacks
+pub const DEFAULT_MEMORY_LIMIT: usize = 32_000_000;
+
+fn fail(msg: &'static str) -> SdkError { SdkError::ScriptError(msg) }
+
+/// One input being spent: its unlocking script (from `tx`) runs, then the output's locking script
+#[derive(Debug, Clone)]
+pub struct Spend<'a> {
+    tx: &'a Transaction,
+    input_index: usize,
+    locking_script: &'a Script,
+    source_satoshis: u64,
+    memory_limit: usize,
+}
+
+impl<'a> Spend<'a> {
+    pub fn new(tx: &'a Transaction, input_index: usize, locking_script: &'a Script, source_satoshis: u64) -> Self {
+        Self { tx, input_index, locking_script, source_satoshis, memory_limit: DEFAULT_MEMORY_LIMIT }
+    }
+
+    pub fn with_memory_limit(mut self, limit: usize) -> Self { self.memory_limit = limit; self }
+
+    /// Run both scripts; `Ok` only when the spend leaves exactly one true element on the stack
+    pub fn validate(&self) -> Result<()> {
+        let input = self.tx.vin.get(self.input_index).ok_or(SdkError::InvalidArgument("spend: input index out of range"))?;
+        let unlocking = Script(input.script_sig.clone());
+        for ins in unlocking.instructions() {
+            if ins.map_err(fail)?.opcode > OP_16 { return Err(fail("unlocking script is not push-only")); }
+        }
+        let mut stacks = Stacks { main: Vec::new(), alt: Vec::new(), memory: 0, limit: self.memory_limit };
+        self.run(&mut stacks, &unlocking)?;
+        while stacks.alt_pop().is_ok() {}
+        self.run(&mut stacks, self.locking_script)?;
+        match stacks.main.as_slice() {
+            [top] if cast_to_bool(top) => Ok(()),
+            [_] => Err(fail("script evaluated to false")),
+            [] => Err(fail("script left an empty stack")),
+            _ => Err(fail("stack is not clean after evaluation")),
+        }
+    }
+
+    fn run(&self, s: &mut Stacks, script: &Script) -> Result<()> {
+        // (branch taken, ELSE already seen) per open IF
+        let mut conds: Vec<(bool, bool)> = Vec::new();
+        let mut code_start = 0;
+        // OP_RETURN inside a branch: nothing more executes, but the branch must still close
+        let mut returned = false;
+        for ins in script.instructions() {
+            let ins = ins.map_err(fail)?;
+            let op = ins.opcode;
+            if matches!(op, OP_2MUL | OP_2DIV | OP_VERIF | OP_VERNOTIF) { return Err(fail("disabled opcode")); }
+            let executing = !returned && conds.iter().all(|c| c.0);
+            if !executing && !(OP_IF..=OP_ENDIF).contains(&op) { continue; }
+            match op {
+                OP_0..=OP_PUSHDATA4 => {
+                    if !is_minimal_push(op, ins.data) { return Err(fail("push is not minimally encoded")); }
+                    s.push(ins.data.to_vec())?;
+                }
+                OP_1NEGATE => s.push(vec![0x81])?,
+                OP_1..=OP_16 => s.push(vec![op - (OP_1 - 1)])?,
+                OP_NOP | OP_NOP1..=OP_NOP10 => {}
+                OP_IF | OP_NOTIF => {
+                    let taken = executing && (cast_to_bool(&s.pop()?) == (op == OP_IF));
+                    conds.push((taken, false));
+                }
+                OP_ELSE => match conds.last_mut() {
+                    Some((taken, seen_else @ false)) => { *taken = !*taken; *seen_else = true; }
+                    Some(_) => return Err(fail("OP_ELSE already seen for this OP_IF")),
+                    None => return Err(fail("OP_ELSE without OP_IF")),
+                },
+                OP_ENDIF => { conds.pop().ok_or(fail("OP_ENDIF without OP_IF"))?; }
+                OP_VERIFY => if !cast_to_bool(&s.pop()?) { return Err(fail("OP_VERIFY failed")) },
+                OP_RETURN if conds.is_empty() => return Ok(()),
+                OP_RETURN => returned = true,
+
+                OP_TOALTSTACK => { let v = s.pop()?; s.memory += v.len(); s.alt.push(v); }
+                OP_FROMALTSTACK => { let v = s.alt_pop()?; s.push(v)?; }
+                OP_2DROP => { s.pop()?; s.pop()?; }
+                OP_2DUP => { s.need(2)?; s.push(s.top(1).clone())?; s.push(s.top(1).clone())?; }
+                OP_3DUP => { s.need(3)?; for _ in 0..3 { s.push(s.top(2).clone())?; } }
+                OP_2OVER => { s.need(4)?; for _ in 0..2 { s.push(s.top(3).clone())?; } }
+                OP_2ROT => { s.need(6)?; let n = s.main.len(); s.main[n - 6..].rotate_left(2); }
+                OP_2SWAP => { s.need(4)?; let n = s.main.len(); s.main[n - 4..].rotate_left(2); }
+                OP_IFDUP => { s.need(1)?; if cast_to_bool(s.top(0)) { s.push(s.top(0).clone())?; } }
+                OP_DEPTH => s.push(BigNumber::from_u64(s.main.len() as u64).to_script_num())?,
+                OP_DROP => { s.pop()?; }
+                OP_DUP => { s.need(1)?; s.push(s.top(0).clone())?; }
+                OP_NIP => { s.need(2)?; let v = s.main.remove(s.main.len() - 2); s.memory -= v.len(); }
+                OP_OVER => { s.need(2)?; s.push(s.top(1).clone())?; }
+                OP_PICK | OP_ROLL => {
+                    let n = s.pop_num()?;
+                    let n = usize::try_from(n.to_i64().unwrap_or(-1)).map_err(|_| fail("OP_PICK/OP_ROLL index out of range"))?;
+                    s.need(n + 1)?;
+                    if op == OP_PICK { s.push(s.top(n).clone())?; } else { let v = s.main.remove(s.main.len() - 1 - n); s.main.push(v); }
+                }
+                OP_ROT => { s.need(3)?; let n = s.main.len(); s.main[n - 3..].rotate_left(1); }
+                OP_SWAP => { s.need(2)?; let n = s.main.len(); s.main.swap(n - 1, n - 2); }
+                OP_TUCK => { s.need(2)?; let v = s.top(0).clone(); s.memory += v.len(); s.check_memory()?; s.main.insert(s.main.len() - 2, v); }
+
+                OP_CAT => {
+                    let (a, b) = s.pop2()?;
+                    s.push([a, b].concat())?;
+                }
+                OP_SPLIT => {
+                    let n = s.pop_num()?;
+                    let mut data = s.pop()?;
+                    let at = usize::try_from(n.to_i64().unwrap_or(-1)).ok().filter(|&at| at <= data.len()).ok_or(fail("OP_SPLIT position out of range"))?;
+                    let tail = data.split_off(at);
+                    s.push(data)?;
+                    s.push(tail)?;
+                }
+                OP_NUM2BIN => {
+                    let size = s.pop_num()?;
+                    let raw = s.pop()?;
+                    let size = usize::try_from(size.to_i64().unwrap_or(-1)).ok().filter(|&n| n <= s.limit).ok_or(fail("OP_NUM2BIN size out of range"))?;
+                    s.push(num2bin(&raw, size)?)?;
+                }
+                OP_BIN2NUM => {
+                    let num = minimally_encode(&s.pop()?);
+                    if num.len() > MAX_SCRIPT_NUM_LENGTH { return Err(fail("OP_BIN2NUM result exceeds number size")); }
+                    s.push(num)?;
+                }
+                OP_SIZE => { s.need(1)?; s.push(BigNumber::from_u64(s.top(0).len() as u64).to_script_num())?; }
+
+                OP_INVERT => { let v = s.pop()?; s.push(v.iter().map(|b| !b).collect())?; }
+                OP_AND | OP_OR | OP_XOR => {
+                    let (a, b) = s.pop2()?;
+                    if a.len() != b.len() { return Err(fail("bitwise operands differ in length")); }
+                    let f: fn(u8, u8) -> u8 = match op { OP_AND => |x, y| x & y, OP_OR => |x, y| x | y, _ => |x, y| x ^ y };
+                    s.push(a.iter().zip(&b).map(|(x, y)| f(*x, *y)).collect())?;
+                }
+                OP_EQUAL | OP_EQUALVERIFY => {
+                    let (a, b) = s.pop2()?;
+                    if op == OP_EQUAL { s.push_bool(a == b)?; } else if a != b { return Err(fail("OP_EQUALVERIFY failed")); }
+                }
+
+                OP_1ADD | OP_1SUB | OP_NEGATE | OP_ABS | OP_NOT | OP_0NOTEQUAL => {
+                    let n = s.pop_num()?;
+                    let out = match op {
+                        OP_1ADD => n + BigNumber::one(),
+                        OP_1SUB => n - BigNumber::one(),
+                        OP_NEGATE => -n,
+                        OP_ABS => n.abs(),
+                        OP_NOT => BigNumber::from_u64(n.is_zero() as u64),
+                        _ => BigNumber::from_u64(!n.is_zero() as u64),
+                    };
+                    s.push(out.to_script_num())?;
+                }
+                OP_LSHIFT | OP_RSHIFT => {
+                    let n = s.pop_num()?;
+                    let data = s.pop()?;
+                    if n.is_neg() { return Err(fail("negative shift")); }
+                    let bits = n.to_i64().ok().and_then(|v| usize::try_from(v).ok()).unwrap_or(usize::MAX);
+                    s.push(if op == OP_LSHIFT { shift_left(&data, bits) } else { shift_right(&data, bits) })?;
+                }
+                OP_ADD..=OP_MAX => {
+                    let b = s.pop_num()?;
+                    let a = s.pop_num()?;
+                    let out = match op {
+                        OP_ADD => &a + &b,
+                        OP_SUB => &a - &b,
+                        OP_MUL => &a * &b,
+                        OP_DIV => a.checked_div(&b).map_err(|_| fail("division by zero"))?,
+                        OP_MOD => a.checked_rem(&b).map_err(|_| fail("modulo by zero"))?,
+                        OP_BOOLAND => BigNumber::from_u64((!a.is_zero() && !b.is_zero()) as u64),
+                        OP_BOOLOR => BigNumber::from_u64((!a.is_zero() || !b.is_zero()) as u64),
+                        OP_NUMEQUAL | OP_NUMEQUALVERIFY => BigNumber::from_u64((a == b) as u64),
+                        OP_NUMNOTEQUAL => BigNumber::from_u64((a != b) as u64),
+                        OP_LESSTHAN => BigNumber::from_u64((a < b) as u64),
+                        OP_GREATERTHAN => BigNumber::from_u64((a > b) as u64),
+                        OP_LESSTHANOREQUAL => BigNumber::from_u64((a <= b) as u64),
+                        OP_GREATERTHANOREQUAL => BigNumber::from_u64((a >= b) as u64),
+                        OP_MIN => a.min(b),
+                        _ => a.max(b),
+                    };
+                    if op == OP_NUMEQUALVERIFY {
+                        if out.is_zero() { return Err(fail("OP_NUMEQUALVERIFY failed")); }
+                    } else {
+                        s.push(out.to_script_num())?;
+                    }
+                }
+                OP_WITHIN => {
+                    let max = s.pop_num()?;
+                    let min = s.pop_num()?;
+                    let x = s.pop_num()?;
+                    s.push_bool(min <= x && x < max)?;
+                }
+
+                OP_RIPEMD160 | OP_SHA1 | OP_SHA256 | OP_HASH160 | OP_HASH256 => {
+                    let v = s.pop()?;
+                    s.push(match op {
+                        OP_RIPEMD160 => { let Ripemd160(h) = ripemd160(&v); h.to_vec() }
+                        OP_SHA1 => { let Sha1(h) = sha1(&v); h.to_vec() }
+                        OP_SHA256 => { let Sha256(h) = sha256(&v); h.to_vec() }
+                        OP_HASH160 => { let Hash160(h) = hash160(&v); h.to_vec() }
+                        _ => sha256d(&v).to_vec(),
+                    })?;
+                }
+                OP_CODESEPARATOR => code_start = ins.end,
+                OP_CHECKSIG | OP_CHECKSIGVERIFY => {
+                    let (sig, pubkey) = s.pop2()?;
+                    check_signature_encoding(&sig)?;
+                    check_pubkey_encoding(&pubkey)?;
+                    let ok = self.check_sig(&sig, &pubkey, &script.as_bytes()[code_start..])?;
+                    if !ok && !sig.is_empty() { return Err(fail("signature must be empty when verification fails (NULLFAIL)")); }
+                    if op == OP_CHECKSIG { s.push_bool(ok)?; } else if !ok { return Err(fail("OP_CHECKSIGVERIFY failed")); }
+                }
+                OP_CHECKMULTISIG | OP_CHECKMULTISIGVERIFY => {
+                    let ok = self.check_multisig(s, &script.as_bytes()[code_start..])?;
+                    if op == OP_CHECKMULTISIG { s.push_bool(ok)?; } else if !ok { return Err(fail("OP_CHECKMULTISIGVERIFY failed")); }
+                }
+                _ => return Err(fail("invalid or reserved opcode executed")),
+            }
+        }
+        if !conds.is_empty() { return Err(fail("unbalanced conditional")); }
+        Ok(())
+    }
+
+    fn check_sig(&self, sig: &[u8], pubkey: &[u8], script_code: &[u8]) -> Result<bool> {
+        let Some((&flag, der)) = sig.split_last() else { return Ok(false) };
+        let hash = sighash(self.tx, self.input_index, script_code, self.source_satoshis, flag)?;
+        let (Ok(sig), Ok(pubkey)) = (Signature::from_der(der), PublicKey::from_sec1_bytes(pubkey)) else { return Ok(false) };
+        Ok(verify_hash(&pubkey, &hash, &sig).unwrap_or(false))
+    }
+
+    // `<dummy> <sig>... m <pubkey>... n`; signatures must match keys in order
+    fn check_multisig(&self, s: &mut Stacks, script_code: &[u8]) -> Result<bool> {
+        let key_count = s.pop_count()?;
+        let mut keys = (0..key_count).map(|_| s.pop()).collect::<Result<Vec<_>>>()?;
+        let sig_count = s.pop_count()?;
+        if sig_count > key_count { return Err(fail("more signatures than keys")); }
+        let mut sigs = (0..sig_count).map(|_| s.pop()).collect::<Result<Vec<_>>>()?;
+        if !s.pop()?.is_empty() { return Err(fail("OP_CHECKMULTISIG dummy must be empty (NULLDUMMY)")); }
+        keys.reverse();
+        sigs.reverse();
+        let (mut isig, mut ikey) = (0, 0);
+        let mut ok = true;
+        while ok && isig < sig_count {
+            check_signature_encoding(&sigs[isig])?;
+            check_pubkey_encoding(&keys[ikey])?;
+            if self.check_sig(&sigs[isig], &keys[ikey], script_code)? { isig += 1; }
+            ikey += 1;
+            ok = sig_count - isig <= key_count - ikey;
+        }
+        if !ok && sigs.iter().any(|sig| !sig.is_empty()) { return Err(fail("signatures must be empty when verification fails (NULLFAIL)")); }
+        Ok(ok)
+    }
+}
+
+// Main and alt stacks with a running byte count for the memory limit
+struct Stacks { main: Vec<Vec<u8>>, alt: Vec<Vec<u8>>, memory: usize, limit: usize }
+
+impl Stacks {
+    fn check_memory(&self) -> Result<()> {
+        if self.memory > self.limit { Err(fail("stack memory usage exceeds limit")) } else { Ok(()) }
+    }
+
+    fn push(&mut self, v: Vec<u8>) -> Result<()> {
+        self.memory += v.len();
+        self.main.push(v);
+        self.check_memory()
+    }
+
+    fn push_bool(&mut self, b: bool) -> Result<()> { self.push(if b { vec![1] } else { Vec::new() }) }
+
+    fn pop(&mut self) -> Result<Vec<u8>> {
+        let v = self.main.pop().ok_or(fail("stack underflow"))?;
+        self.memory -= v.len();
+        Ok(v)
+    }
+
+    fn alt_pop(&mut self) -> Result<Vec<u8>> {
+        let v = self.alt.pop().ok_or(fail("alt stack underflow"))?;
+        self.memory -= v.len();
+        Ok(v)
+    }
+
+    // Second-from-top and top, in stack order
+    fn pop2(&mut self) -> Result<(Vec<u8>, Vec<u8>)> {
+        self.need(2)?;
+        let b = self.pop()?;
+        Ok((self.pop()?, b))
+    }
+
+    fn pop_num(&mut self) -> Result<BigNumber> {
+        BigNumber::from_script_num(&self.pop()?, true, Some(MAX_SCRIPT_NUM_LENGTH)).map_err(|_| fail("invalid script number"))
+    }
+
+    fn pop_count(&mut self) -> Result<usize> {
+        let n = self.pop_num()?;
+        usize::try_from(n.to_i64().unwrap_or(-1)).ok().filter(|&n| n <= self.main.len()).ok_or(fail("OP_CHECKMULTISIG count out of range"))
+    }
+
+    fn need(&self, n: usize) -> Result<()> {
+        if self.main.len() < n { Err(fail("stack underflow")) } else { Ok(()) }
+    }
+
+    // `depth` elements below the top; callers check `need` first
+    fn top(&self, depth: usize) -> &Vec<u8> { &self.main[self.main.len() - 1 - depth] }
+}
+
+/// Script truthiness: any non-zero byte, except a lone sign bit in the last byte (negative zero)
+pub fn cast_to_bool(v: &[u8]) -> bool {
+    match v.split_last() {
+        Some((&last, rest)) => rest.iter().any(|&b| b != 0) || (last != 0 && last != 0x80),
+        None => false,
+    }
+}
+
+fn num2bin(raw: &[u8], size: usize) -> Result<Vec<u8>> {
+    let mut num = minimally_encode(raw);
+    if num.len() > size { return Err(fail("OP_NUM2BIN value does not fit requested size")); }
+    if num.len() < size {
+        let sign = num.last_mut().map_or(0, |b| { let s = *b & 0x80; *b &= 0x7f; s });
+        num.resize(size - 1, 0);
+        num.push(sign);
+    }
+    Ok(num)
+}
+
+// Shift the whole array as one big-endian bit string, keeping its length
+fn shift_left(data: &[u8], bits: usize) -> Vec<u8> {
+    let (bytes, bits) = (bits / 8, (bits % 8) as u32);
+    let at = |j: Option<usize>| j.and_then(|j| data.get(j)).copied().unwrap_or(0);
+    (0..data.len()).map(|i| {
+        let src = i.checked_add(bytes);
+        let lo = if bits == 0 { 0 } else { at(src.and_then(|j| j.checked_add(1))) >> (8 - bits) };
+        (at(src) << bits) | lo
+    }).collect()
+}
+
+fn shift_right(data: &[u8], bits: usize) -> Vec<u8> {
+    let (bytes, bits) = (bits / 8, (bits % 8) as u32);
+    (0..data.len()).map(|i| {
+        let lo = i.checked_sub(bytes).map_or(0, |j| data[j] >> bits);
+        let hi = if bits == 0 { 0 } else { i.checked_sub(bytes).and_then(|j| j.checked_sub(1)).map_or(0, |j| data[j] << (8 - bits)) };
+        hi | lo
+    }).collect()
+}
+
+fn check_signature_encoding(sig: &[u8]) -> Result<()> {
+    let Some((&flag, der)) = sig.split_last() else { return Ok(()) };
+    let parsed = Signature::from_der(der).map_err(|_| fail("signature is not strict DER"))?;
+    if !parsed.is_low_s() { return Err(fail("signature S value is not low")); }
+    if SigHashType::from_flag(flag).is_none() { return Err(fail("undefined sighash type")); }
+    if flag & SIGHASH_FORKID == 0 { return Err(fail("signature is missing SIGHASH_FORKID")); }
+    Ok(())
+}
+
+fn check_pubkey_encoding(pubkey: &[u8]) -> Result<()> {
+    match (pubkey.len(), pubkey.first()) {
+        (33, Some(0x02 | 0x03)) | (65, Some(0x04)) => Ok(()),
+        _ => Err(fail("public key is not strictly encoded")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{sign_hash, PrivateKey};
+    use crate::script::templates::{P2pkhTemplate, PushDropTemplate, RPuzzleTemplate};
+    use crate::transaction::{OutPoint, TxBuilder};
+
+    const SATS: u64 = 10_000;
+
+    fn spending(unlocking: Script) -> Transaction {
+        TxBuilder::new()
+            .input_with(OutPoint { txid: [9; 32], vout: 0 }, unlocking, 0xffff_ffff)
+            .output(9_000, Script::new().push_opcode(OP_1))
+            .build()
+    }
+
+    // Evaluate `unlocking` against `locking` in a throwaway transaction
+    fn eval(unlocking: Script, locking: &Script) -> Result<()> {
+        Spend::new(&spending(unlocking), 0, locking, SATS).validate()
+    }
+
+    fn ops(ops: &[u8]) -> Script { ops.iter().fold(Script::new(), |s, &op| s.push_opcode(op)) }
+
+    fn signed_p2pkh(key: &PrivateKey, locking: &Script) -> Transaction {
+        let mut tx = spending(Script::new());
+        let flag = SigHashType::All.flag(false);
+        let hash = sighash(&tx, 0, locking.as_bytes(), SATS, flag).unwrap();
+        let sig = sign_hash(key, &hash).unwrap();
+        tx.vin[0].script_sig = P2pkhTemplate::unlocking_script_signed(&sig, flag, &key.to_public_key()).into_bytes();
+        tx
+    }
+
+    #[test]
+    fn p2pkh_spend() {
+        let key = PrivateKey::from_bytes(&[0x11; 32]).unwrap();
+        let Hash160(h) = hash160(key.to_public_key().as_bytes());
+        let locking = P2pkhTemplate::locking_script(h);
+        let tx = signed_p2pkh(&key, &locking);
+        assert!(Spend::new(&tx, 0, &locking, SATS).validate().is_ok());
+        // the sighash commits to the spent amount
+        assert!(Spend::new(&tx, 0, &locking, SATS + 1).validate().is_err());
+        let other = PrivateKey::from_bytes(&[0x22; 32]).unwrap();
+        assert!(Spend::new(&signed_p2pkh(&other, &locking), 0, &locking, SATS).validate().is_err());
+    }
+
+    #[test]
+    fn checksig_nullfail_and_encoding() {
+        let key = PrivateKey::from_bytes(&[0x11; 32]).unwrap();
+        let pubkey = key.to_public_key();
+        let p2pk = Script::new().push_data(pubkey.as_bytes()).push_opcode(OP_CHECKSIG);
+        let not_p2pk = Script::new().push_data(pubkey.as_bytes()).push_opcode(OP_CHECKSIG).push_opcode(OP_NOT);
+        // an empty signature is a clean failure
+        assert!(eval(Script::new().push_opcode(OP_0), &not_p2pk).is_ok());
+        let mut tx = spending(Script::new());
+        let hash = sighash(&tx, 0, p2pk.as_bytes(), SATS, SigHashType::All.flag(false)).unwrap();
+        let mut sig = sign_hash(&key, &hash).unwrap().to_der();
+        sig.push(SigHashType::All.flag(false));
+        tx.vin[0].script_sig = Script::new().push_data(&sig).into_bytes();
+        assert!(Spend::new(&tx, 0, &p2pk, SATS).validate().is_ok());
+        // a well-formed but wrong signature may not be used to make CHECKSIG return false
+        assert!(matches!(Spend::new(&tx, 0, &not_p2pk, SATS).validate(), Err(SdkError::ScriptError(_))));
+        *sig.last_mut().unwrap() = 0x01;
+        tx.vin[0].script_sig = Script::new().push_data(&sig).into_bytes();
+        assert!(Spend::new(&tx, 0, &p2pk, SATS).validate().is_err());
+    }
+
+    #[test]
+    fn checksig_script_code_follows_codeseparator() {
+        let key = PrivateKey::from_bytes(&[0x11; 32]).unwrap();
+        let p2pk = Script::new().push_data(key.to_public_key().as_bytes()).push_opcode(OP_CHECKSIG);
+        let flag = SigHashType::All.flag(false);
+        let spend = |locking: &Script, script_code: &[u8]| {
+            let mut tx = spending(Script::new());
+            let mut sig = sign_hash(&key, &sighash(&tx, 0, script_code, SATS, flag).unwrap()).unwrap().to_der();
+            sig.push(flag);
+            tx.vin[0].script_sig = Script::new().push_data(&sig).into_bytes();
+            Spend::new(&tx, 0, locking, SATS).validate()
+        };
+        // executed: the signature covers only what follows the separator
+        let mut executed = ops(&[OP_1, OP_DROP, OP_CODESEPARATOR]);
+        executed.0.extend_from_slice(p2pk.as_bytes());
+        assert!(spend(&executed, p2pk.as_bytes()).is_ok());
+        assert!(spend(&executed, executed.as_bytes()).is_err());
+        // in a branch that is skipped the separator has no effect
+        let mut skipped = ops(&[OP_0, OP_IF, OP_CODESEPARATOR, OP_ENDIF]);
+        skipped.0.extend_from_slice(p2pk.as_bytes());
+        assert!(spend(&skipped, skipped.as_bytes()).is_ok());
+        assert!(spend(&skipped, p2pk.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn multisig_requires_null_dummy() {
+        let keys: Vec<PrivateKey> = (1..=3u8).map(|i| PrivateKey::from_bytes(&[i; 32]).unwrap()).collect();
+        let locking = keys.iter().fold(Script::new().push_opcode(OP_2), |s, k| s.push_data(k.to_public_key().as_bytes()))
+            .push_opcode(OP_3).push_opcode(OP_CHECKMULTISIG);
+        let flag = SigHashType::All.flag(false);
+        let mut tx = spending(Script::new());
+        let hash = sighash(&tx, 0, locking.as_bytes(), SATS, flag).unwrap();
+        let sig = |k: &PrivateKey| { let mut d = sign_hash(k, &hash).unwrap().to_der(); d.push(flag); d };
+        tx.vin[0].script_sig = Script::new().push_opcode(OP_0).push_data(&sig(&keys[0])).push_data(&sig(&keys[2])).into_bytes();
+        assert!(Spend::new(&tx, 0, &locking, SATS).validate().is_ok());
+        tx.vin[0].script_sig = Script::new().push_opcode(OP_1).push_data(&sig(&keys[0])).push_data(&sig(&keys[2])).into_bytes();
+        assert!(Spend::new(&tx, 0, &locking, SATS).validate().is_err());
+        // out of order signatures fail, and NULLFAIL turns that into an error
+        tx.vin[0].script_sig = Script::new().push_opcode(OP_0).push_data(&sig(&keys[2])).push_data(&sig(&keys[0])).into_bytes();
+        assert!(Spend::new(&tx, 0, &locking, SATS).validate().is_err());
+    }
+
+    #[test]
+    fn template_spends() {
+        let Sha256(digest) = sha256(&[7u8; 32]);
+        let puzzle = RPuzzleTemplate::locking_script_sha256(digest);
+        assert!(eval(RPuzzleTemplate::unlocking_script_preimage(&[7u8; 32]), &puzzle).is_ok());
+        assert!(eval(RPuzzleTemplate::unlocking_script_preimage(&[8u8; 32]), &puzzle).is_err());
+        assert!(eval(Script::new().push_opcode(OP_1), &PushDropTemplate::locking_script(b"token")).is_ok());
+    }
+
+    #[test]
+    fn arithmetic_and_bsv_opcodes() {
+        let ok = |locking: Script| eval(Script::new(), &locking);
+        let n = |v: i64| Script::new().push_data(&BigNumber::from_i64(v).to_script_num());
+        assert!(ok(n(-7).push_opcode(OP_2).push_opcode(OP_DIV).push_data(&[0x83]).push_opcode(OP_NUMEQUAL)).is_ok());
+        assert!(ok(n(-7).push_opcode(OP_2).push_opcode(OP_MOD).push_opcode(OP_1NEGATE).push_opcode(OP_NUMEQUAL)).is_ok());
+        assert!(ok(ops(&[OP_1, OP_0]).push_opcode(OP_DIV)).is_err());
+        assert!(ok(ops(&[OP_1, OP_0, OP_MOD])).is_err());
+        assert!(ok(n(1_000_000).push_opcode(OP_DUP).push_opcode(OP_MUL).push_data(&BigNumber::from_i64(1_000_000_000_000).to_script_num()).push_opcode(OP_NUMEQUAL)).is_ok());
+        assert!(ok(Script::new().push_data(b"ab").push_data(b"cd").push_opcode(OP_CAT).push_opcode(OP_2).push_opcode(OP_SPLIT)
+            .push_data(b"cd").push_opcode(OP_EQUALVERIFY).push_data(b"ab").push_opcode(OP_EQUAL)).is_ok());
+        assert!(ok(Script::new().push_data(b"ab").push_opcode(OP_3).push_opcode(OP_SPLIT)).is_err());
+        assert!(ok(ops(&[OP_1NEGATE, OP_4]).push_opcode(OP_NUM2BIN).push_data(&[1, 0, 0, 0x80]).push_opcode(OP_EQUAL)).is_ok());
+        assert!(ok(Script::new().push_data(&[5, 0, 0, 0]).push_opcode(OP_BIN2NUM).push_opcode(OP_5).push_opcode(OP_EQUAL)).is_ok());
+        assert!(ok(Script::new().push_data(&[0x01, 0x80]).push_opcode(OP_1).push_opcode(OP_LSHIFT).push_data(&[0x03, 0x00]).push_opcode(OP_EQUAL)).is_ok());
+        assert!(ok(Script::new().push_data(&[0x01, 0x80]).push_opcode(OP_9).push_opcode(OP_RSHIFT).push_data(&[0x00, 0x00]).push_opcode(OP_EQUAL)).is_ok());
+        assert!(ok(Script::new().push_data(&[0x0f, 0xf0]).push_data(&[0xff, 0x00]).push_opcode(OP_XOR).push_data(&[0xf0, 0xf0]).push_opcode(OP_EQUAL)).is_ok());
+        assert!(ok(Script::new().push_data(&[0x0f]).push_data(&[0xff, 0x00]).push_opcode(OP_AND)).is_err());
+        assert!(ok(ops(&[OP_3, OP_2, OP_5, OP_WITHIN])).is_ok());
+        assert!(ok(ops(&[OP_5, OP_2, OP_5, OP_WITHIN, OP_NOT])).is_ok());
+    }
+
+    #[test]
+    fn conditionals_and_stacks() {
+        let ok = |locking: Script| eval(Script::new(), &locking);
+        assert!(ok(ops(&[OP_0, OP_IF, OP_0, OP_ELSE, OP_1, OP_ENDIF])).is_ok());
+        assert!(ok(ops(&[OP_1, OP_NOTIF, OP_RESERVED, OP_ELSE, OP_1, OP_ENDIF])).is_ok());
+        assert!(ok(ops(&[OP_1, OP_IF, OP_1, OP_ELSE, OP_0, OP_ELSE, OP_1, OP_ENDIF])).is_err());
+        assert!(ok(ops(&[OP_1, OP_IF, OP_1])).is_err());
+        assert!(ok(ops(&[OP_ENDIF, OP_1])).is_err());
+        assert!(ok(ops(&[OP_7, OP_TOALTSTACK, OP_1, OP_FROMALTSTACK, OP_7, OP_EQUALVERIFY])).is_ok());
+        assert!(ok(ops(&[OP_1, OP_2, OP_3, OP_ROT, OP_1, OP_EQUALVERIFY, OP_2DROP, OP_1])).is_ok());
+        assert!(ok(ops(&[OP_1, OP_2, OP_3, OP_2, OP_PICK, OP_1, OP_EQUALVERIFY, OP_DEPTH, OP_3, OP_EQUALVERIFY, OP_2DROP])).is_ok());
+        assert!(ok(ops(&[OP_1, OP_RETURN, OP_RESERVED])).is_ok());
+        assert!(ok(ops(&[OP_1, OP_1, OP_IF, OP_RETURN])).is_err());
+        assert!(ok(ops(&[OP_1, OP_1, OP_IF, OP_RETURN, OP_ELSE, OP_RESERVED, OP_ENDIF])).is_ok());
+        // the alt stack does not survive into the locking script
+        assert!(eval(Script::new(), &ops(&[OP_FROMALTSTACK])).is_err());
+    }
+
+    #[test]
+    fn rejections() {
+        assert!(eval(Script::new(), &ops(&[OP_0, OP_IF, OP_2MUL, OP_ENDIF, OP_1])).is_err());
+        assert!(eval(Script::new(), &ops(&[OP_1, OP_IF, OP_1, OP_ENDIF, 0xba])).is_err());
+        assert!(eval(Script::new(), &ops(&[OP_0, OP_IF, 0xba, OP_ENDIF, OP_1])).is_ok());
+        assert!(eval(ops(&[OP_1, OP_DUP]), &ops(&[OP_DROP])).is_err());
+        assert!(eval(ops(&[OP_1, OP_1]), &Script::new()).is_err());
+        assert!(eval(Script::new(), &ops(&[OP_0])).is_err());
+        assert!(eval(Script(vec![0x01, 0x05]), &Script::new()).is_err());
+        assert!(eval(Script::new(), &Script::new().push_data(&[0u8; 64]).push_opcode(OP_DUP).push_opcode(OP_CAT).push_opcode(OP_SIZE).push_opcode(OP_NIP))
+            .is_ok());
+        let spend_tx = spending(Script::new());
+        let big = Script::new().push_data(&[0u8; 64]).push_opcode(OP_DUP).push_opcode(OP_CAT);
+        assert!(Spend::new(&spend_tx, 0, &big, SATS).with_memory_limit(100).validate().is_err());
+    }
+
+    #[test]
+    fn minimal_push_helpers() {
+        assert!(is_minimal_push(OP_0, &[]));
+        assert!(!is_minimal_push(0x01, &[5]));
+        assert!(!is_minimal_push(OP_PUSHDATA1, &[0; 10]));
+        assert!(!cast_to_bool(&[0, 0x80]) && cast_to_bool(&[0, 1]));
+    }
+}
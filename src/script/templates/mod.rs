@@ -4,6 +4,6 @@ pub mod p2pkh;
 pub mod push_drop;
 pub mod r_puzzle;
 
-pub use p2pkh::P2pkhTemplate;
-pub use push_drop::PushDropTemplate;
-pub use r_puzzle::RPuzzleTemplate;
+pub use p2pkh::{P2pkhTemplate, P2pkhUnlocker};
+pub use push_drop::{PushDropTemplate, PushDropUnlocker};
+pub use r_puzzle::{RPuzzleTemplate, RPuzzleUnlocker};
@@ -1,5 +1,12 @@
 // P2PKH script template
-use super::super::{Script, OP_DUP, OP_HASH160, OP_EQUALVERIFY, OP_CHECKSIG};
+use super::super::{Script, ScriptTemplate, UnlockingScriptTemplate, OP_DUP, OP_HASH160, OP_EQUALVERIFY, OP_CHECKSIG};
+use crate::crypto::{sign_hash, PrivateKey, PublicKey, Signature};
+use crate::error::Result;
+use crate::transaction::sighash::{sighash, SigHashType};
+use crate::transaction::{Transaction, TxOut};
+
+// `<DER sig + sighash byte> <compressed pubkey>` at its longest: 1 + 73 + 1 + 33
+const UNLOCKING_SCRIPT_MAX_LEN: usize = 108;
 
 #[derive(Debug, Default, Clone)]
 pub struct P2pkhTemplate;
@@ -18,4 +25,41 @@ impl P2pkhTemplate {
             .push_data(sig)
             .push_data(pubkey)
     }
+    // Typed variant: pushes DER || sighash flag byte, then the compressed public key
+    pub fn unlocking_script_signed(sig: &Signature, sighash_flag: u8, pubkey: &PublicKey) -> Script {
+        let mut checksig = sig.to_der();
+        checksig.push(sighash_flag);
+        Self::unlocking_script(&checksig, pubkey.as_bytes())
+    }
+}
+
+impl ScriptTemplate for P2pkhTemplate {
+    type LockParams = [u8; 20];
+    /// Signing key and the output being spent
+    type UnlockParams = (PrivateKey, TxOut);
+    type Unlocker = P2pkhUnlocker;
+
+    fn lock(&self, pubkey_hash: [u8; 20]) -> Script { Self::locking_script(pubkey_hash) }
+
+    fn unlock(&self, (key, source): (PrivateKey, TxOut)) -> P2pkhUnlocker {
+        P2pkhUnlocker { key, source, sighash_flag: SigHashType::All.flag(false) }
+    }
+}
+
+/// Signs a P2PKH input, with SIGHASH_ALL|FORKID unless another flag is chosen
+#[derive(Debug, Clone)]
+pub struct P2pkhUnlocker { key: PrivateKey, source: TxOut, sighash_flag: u8 }
+
+impl P2pkhUnlocker {
+    pub fn with_sighash_flag(mut self, flag: u8) -> Self { self.sighash_flag = flag; self }
+}
+
+impl UnlockingScriptTemplate for P2pkhUnlocker {
+    fn sign(&self, tx: &Transaction, input_index: usize) -> Result<Script> {
+        let hash = sighash(tx, input_index, &self.source.script_pubkey, self.source.value, self.sighash_flag)?;
+        let sig = sign_hash(&self.key, &hash)?;
+        Ok(P2pkhTemplate::unlocking_script_signed(&sig, self.sighash_flag, &self.key.to_public_key()))
+    }
+
+    fn estimate_length(&self, _tx: &Transaction, _input_index: usize) -> usize { UNLOCKING_SCRIPT_MAX_LEN }
 }
@@ -1,5 +1,8 @@
 // PushDrop script template
-use super::super::{Script, OP_DROP};
+use alloc::vec::Vec;
+use super::super::{Script, ScriptTemplate, UnlockingScriptTemplate, OP_DROP, OP_TRUE};
+use crate::error::Result;
+use crate::transaction::Transaction;
 
 #[derive(Debug, Default, Clone)]
 pub struct PushDropTemplate;
@@ -12,3 +15,21 @@ impl PushDropTemplate {
             .push_opcode(OP_DROP)
     }
 }
+
+impl ScriptTemplate for PushDropTemplate {
+    type LockParams = Vec<u8>;
+    type UnlockParams = ();
+    type Unlocker = PushDropUnlocker;
+
+    fn lock(&self, data: Vec<u8>) -> Script { Self::locking_script(&data) }
+    fn unlock(&self, _: ()) -> PushDropUnlocker { PushDropUnlocker }
+}
+
+/// `<data> OP_DROP` leaves nothing behind, so the spend only has to push true
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PushDropUnlocker;
+
+impl UnlockingScriptTemplate for PushDropUnlocker {
+    fn sign(&self, _tx: &Transaction, _input_index: usize) -> Result<Script> { Ok(Script::new().push_opcode(OP_TRUE)) }
+    fn estimate_length(&self, _tx: &Transaction, _input_index: usize) -> usize { 1 }
+}
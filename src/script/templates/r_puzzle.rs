@@ -1,5 +1,8 @@
 // RPuzzle script template
-use super::super::{Script, OP_DROP, OP_SIZE, OP_SHA256, OP_EQUAL, OP_EQUALVERIFY};
+use alloc::vec::Vec;
+use super::super::{Script, ScriptTemplate, UnlockingScriptTemplate, OP_DROP, OP_SIZE, OP_SHA256, OP_EQUAL, OP_EQUALVERIFY};
+use crate::error::Result;
+use crate::transaction::Transaction;
 
 #[derive(Debug, Default, Clone)]
 pub struct RPuzzleTemplate;
@@ -29,3 +32,27 @@ impl RPuzzleTemplate {
         Script::new().push_data(preimage)
     }
 }
+
+impl ScriptTemplate for RPuzzleTemplate {
+    /// SHA-256 digest of the preimage
+    type LockParams = [u8; 32];
+    /// The preimage itself
+    type UnlockParams = Vec<u8>;
+    type Unlocker = RPuzzleUnlocker;
+
+    fn lock(&self, digest32: [u8; 32]) -> Script { Self::locking_script_sha256(digest32) }
+    fn unlock(&self, preimage: Vec<u8>) -> RPuzzleUnlocker { RPuzzleUnlocker { preimage } }
+}
+
+#[derive(Debug, Clone)]
+pub struct RPuzzleUnlocker { preimage: Vec<u8> }
+
+impl UnlockingScriptTemplate for RPuzzleUnlocker {
+    fn sign(&self, _tx: &Transaction, _input_index: usize) -> Result<Script> {
+        Ok(RPuzzleTemplate::unlocking_script_preimage(&self.preimage))
+    }
+
+    fn estimate_length(&self, _tx: &Transaction, _input_index: usize) -> usize {
+        RPuzzleTemplate::unlocking_script_preimage(&self.preimage).as_bytes().len()
+    }
+}
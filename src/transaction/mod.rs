@@ -1,6 +1,8 @@
 // Transaction primitives and builder (placeholders)
+use alloc::{boxed::Box, vec, vec::Vec};
 use crate::error::{Result, SdkError};
-use crate::script::{Script, templates::P2pkhTemplate};
+use crate::script::{Script, UnlockingScriptTemplate, templates::P2pkhTemplate};
+use core::fmt;
 
 // Submodules for sighash logic
 pub mod sighash;
@@ -29,6 +31,10 @@ fn put_varint(buf: &mut Vec<u8>, n: u64) {
     else { buf.push(0xFF); buf.extend_from_slice(&n.to_le_bytes()); }
 }
 
+fn varint_len(n: u64) -> usize {
+    match n { 0..=0xFC => 1, 0xFD..=0xFFFF => 3, 0x1_0000..=0xFFFF_FFFF => 5, _ => 9 }
+}
+
 fn get_varint(data: &[u8], i: &mut usize) -> Result<u64> {
     if *i >= data.len() { return Err(SdkError::ParseError("varint: EOF")); }
     let p = data[*i]; *i += 1;
@@ -96,11 +102,19 @@ pub fn deserialize(data: &[u8]) -> Result<Transaction> {
 
 pub const SEQUENCE_FINAL: u32 = 0xFFFF_FFFF;
 
-#[derive(Debug, Default)]
-pub struct TxBuilder { tx: Transaction }
+/// Assembles a transaction; inputs added with an unlocker are signed by [`TxBuilder::build_signed`]
+#[derive(Default)]
+pub struct TxBuilder { tx: Transaction, unlockers: Vec<(usize, Box<dyn UnlockingScriptTemplate>)> }
+
+impl fmt::Debug for TxBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let signed_inputs: Vec<usize> = self.unlockers.iter().map(|(i, _)| *i).collect();
+        f.debug_struct("TxBuilder").field("tx", &self.tx).field("signed_inputs", &signed_inputs).finish()
+    }
+}
 
 impl TxBuilder {
-    pub fn new() -> Self { Self { tx: Transaction { version: 1, vin: vec![], vout: vec![], locktime: 0 } } }
+    pub fn new() -> Self { Self { tx: Transaction { version: 1, vin: vec![], vout: vec![], locktime: 0 }, unlockers: vec![] } }
 
     pub fn version(mut self, v: i32) -> Self { self.tx.version = v; self }
     pub fn locktime(mut self, lt: u32) -> Self { self.tx.locktime = lt; self }
@@ -115,6 +129,12 @@ impl TxBuilder {
         self
     }
 
+    /// Input whose unlocking script `unlocker` produces once the transaction is complete
+    pub fn input_unlocking(mut self, prevout: OutPoint, unlocker: impl UnlockingScriptTemplate + 'static) -> Self {
+        self.unlockers.push((self.tx.vin.len(), Box::new(unlocker)));
+        self.input(prevout)
+    }
+
     pub fn output(mut self, value: u64, script_pubkey: Script) -> Self {
         self.tx.vout.push(TxOut { value, script_pubkey: script_pubkey.into_bytes() });
         self
@@ -126,5 +146,25 @@ impl TxBuilder {
         self
     }
 
+    /// Serialized size with every unlocker's script at its estimated length, for fees before signing
+    pub fn estimated_size(&self) -> usize {
+        let base = serialize(&self.tx).len();
+        self.unlockers.iter().fold(base, |size, (i, unlocker)| {
+            let current = self.tx.vin[*i].script_sig.len();
+            let estimate = unlocker.estimate_length(&self.tx, *i);
+            size - varint_len(current as u64) - current + varint_len(estimate as u64) + estimate
+        })
+    }
+
+    /// The transaction as built, leaving unlocker inputs unsigned
     pub fn build(self) -> Transaction { self.tx }
+
+    /// The transaction with each unlocker's script filled in
+    pub fn build_signed(self) -> Result<Transaction> {
+        let mut tx = self.tx;
+        for (i, unlocker) in &self.unlockers {
+            tx.vin[*i].script_sig = unlocker.sign(&tx, *i)?.into_bytes();
+        }
+        Ok(tx)
+    }
 }
@@ -1,7 +1,10 @@
 // Utility helpers (buffer, hex, varint placeholders)
-use crate::error::{Result, SdkError};
+use alloc::{string::String, vec::Vec};
+use crate::error::{Base58Error, Result, SdkError};
+use base64::{engine::general_purpose::STANDARD, Engine};
 use bs58;
 use crate::crypto::sha256d;
+use zeroize::Zeroize;
 
 pub fn hex_encode(bytes: &[u8]) -> String {
     const HEX: &[u8; 16] = b"0123456789abcdef";
@@ -15,7 +18,7 @@ pub fn hex_encode(bytes: &[u8]) -> String {
 
 pub fn hex_decode(s: &str) -> Result<Vec<u8>> {
     let s = s.trim();
-    if s.len() % 2 != 0 { return Err(SdkError::InvalidArgument("hex must have even length")); }
+    if !s.len().is_multiple_of(2) { return Err(SdkError::InvalidArgument("hex must have even length")); }
     let mut out = Vec::with_capacity(s.len() / 2);
     let bytes = s.as_bytes();
     for i in (0..bytes.len()).step_by(2) {
@@ -84,17 +87,27 @@ pub fn base58check_encode(version: u8, payload: &[u8]) -> String {
     raw.extend_from_slice(payload);
     let checksum_full = sha256d(&raw);
     raw.extend_from_slice(&checksum_full[0..4]);
-    bs58::encode(raw).into_string()
+    let s = bs58::encode(&raw).into_string();
+    // payloads may be secret (WIF, xprv)
+    raw.zeroize();
+    s
 }
 
 pub fn base58check_decode(s: &str) -> Result<(u8, Vec<u8>)> {
-    let raw = bs58::decode(s).into_vec().map_err(|_| SdkError::InvalidArgument("invalid base58"))?;
-    if raw.len() < 5 { return Err(SdkError::InvalidArgument("base58check too short")); }
+    let mut raw = bs58::decode(s).into_vec().map_err(|_| Base58Error::InvalidCharacter)?;
+    if raw.len() < 5 { return Err(Base58Error::TooShort.into()); }
     let (ver_and_payload, check) = raw.split_at(raw.len() - 4);
     let calc = sha256d(ver_and_payload);
-    if check != &calc[0..4] { return Err(SdkError::InvalidArgument("bad base58check checksum")); }
-    if ver_and_payload.is_empty() { return Err(SdkError::InvalidArgument("empty payload")); }
-    Ok((ver_and_payload[0], ver_and_payload[1..].to_vec()))
+    let decoded = if check == &calc[0..4] { Ok((ver_and_payload[0], ver_and_payload[1..].to_vec())) } else { Err(Base58Error::BadChecksum.into()) };
+    raw.zeroize();
+    decoded
+}
+
+// Standard (padded) base64, as used by BSM signatures
+pub fn base64_encode(bytes: &[u8]) -> String { STANDARD.encode(bytes) }
+
+pub fn base64_decode(s: &str) -> Result<Vec<u8>> {
+    STANDARD.decode(s.trim()).map_err(|_| SdkError::InvalidArgument("invalid base64"))
 }
 
 #[cfg(test)]
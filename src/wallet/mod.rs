@@ -1,9 +1,12 @@
 // Wallet façade over keys, storage, and network
-use crate::{crypto::KeyPair, storage::Storage, error::{Result, SdkError}};
+use crate::{address::{self, Address, Network}, crypto::KeyPair, storage::Storage, error::{Result, SdkError}};
+use crate::script::{templates::{P2pkhTemplate, P2pkhUnlocker}, ScriptTemplate};
+use crate::transaction::TxOut;
 
 #[derive(Debug, Default)]
 pub struct WalletConfig {
-    pub network: String,
+    /// Chain the wallet operates on; `Network::Custom` for private chains
+    pub network: Network,
 }
 
 #[derive(Debug, Default)]
@@ -16,5 +19,11 @@ pub struct Wallet<S: Storage> {
 impl<S: Storage> Wallet<S> {
     pub fn new(cfg: WalletConfig, storage: S) -> Self { Self { cfg, keypair: None, storage } }
     pub fn set_keypair(&mut self, kp: KeyPair) { self.keypair = Some(kp) }
+    /// Receive address of the current key on the configured network
+    pub fn address(&self) -> Option<Address> { self.keypair.as_ref().map(|kp| address::from_pubkey(&kp.public, self.cfg.network)) }
+    /// Unlocker spending `source`, one of this wallet's P2PKH outputs, for [`TxBuilder::input_unlocking`](crate::transaction::TxBuilder::input_unlocking)
+    pub fn p2pkh_unlocker(&self, source: TxOut) -> Option<P2pkhUnlocker> {
+        self.keypair.as_ref().map(|kp| P2pkhTemplate.unlock((kp.private.clone(), source)))
+    }
     pub fn balance(&self) -> Result<u64> { Err(SdkError::NotImplemented("Wallet::balance")) }
 }
@@ -0,0 +1,72 @@
+use rs_sdk::script::{templates::P2pkhTemplate, Chunk, Opcode, Script, OP_1NEGATE, OP_CHECKSIG, OP_DROP, OP_FALSE, OP_PUSHDATA1, OP_RETURN};
+
+#[test]
+fn p2pkh_to_asm() {
+    let s = P2pkhTemplate::locking_script([0x11; 20]);
+    assert_eq!(s.to_asm().unwrap(), "OP_DUP OP_HASH160 1111111111111111111111111111111111111111 OP_EQUALVERIFY OP_CHECKSIG");
+    assert_eq!(Script::from_asm(&s.to_asm().unwrap()).unwrap(), s);
+}
+
+#[test]
+fn special_tokens_and_aliases() {
+    let s = Script::new().push_opcode(OP_FALSE).push_opcode(OP_RETURN).push_data(b"hi").push_opcode(OP_1NEGATE);
+    assert_eq!(s.to_asm().unwrap(), "0 OP_RETURN 6869 -1");
+    assert_eq!(Script::from_asm("OP_FALSE OP_RETURN 6869 -1").unwrap(), s);
+    assert_eq!(Script::from_asm("OP_TRUE").unwrap(), Script::from_asm("OP_1").unwrap());
+    // odd-length hex is left-padded, as in ts-sdk
+    assert_eq!(Script::from_asm("abc").unwrap().into_bytes(), vec![0x02, 0x0a, 0xbc]);
+    assert_eq!(Script::from_asm("  OP_NOP \n OP_CHECKSIG ").unwrap().into_bytes(), vec![0x61, OP_CHECKSIG]);
+}
+
+#[test]
+fn explicit_pushdata_form() {
+    let s = Script::from_asm("OP_PUSHDATA1 2 aabb").unwrap();
+    assert_eq!(s.as_bytes(), &[OP_PUSHDATA1, 2, 0xaa, 0xbb]);
+    assert_eq!(s.to_asm().unwrap(), "aabb");
+    assert!(Script::from_asm("OP_PUSHDATA2 2").is_err());
+    assert!(Script::from_asm("OP_PUSHDATA1 5 aabb").is_err());
+    assert!(Script::from_asm("OP_PUSHDATA1 0x02 aabb").is_err());
+    assert_eq!(Script::from_asm("OP_PUSHBYTES_2 aabb").unwrap().as_bytes(), &[0x02, 0xaa, 0xbb]);
+    assert!(Script::from_asm("OP_PUSHBYTES_3").is_err());
+    assert!(Script::from_asm("OP_PUSHBYTES_3 aabb").is_err());
+    assert!(Script::from_asm("OP_PUSHBYTES_3 OP_DUP").is_err());
+}
+
+#[test]
+fn bitcoind_dialect() {
+    // decodescript of the genesis coinbase scriptSig and output script
+    let coinbase = "486604799 4 5468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73";
+    let parsed = Script::from_bitcoind_asm(coinbase).unwrap();
+    assert_eq!(parsed.to_bitcoind_asm().unwrap(), coinbase);
+    // the 4 was a non-minimal one-byte push; it comes back as OP_4
+    assert_eq!(&parsed.as_bytes()[..6], &[0x04, 0xff, 0xff, 0x00, 0x1d, 0x54]);
+    let output = "04678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5f OP_CHECKSIG";
+    let parsed = Script::from_bitcoind_asm(output).unwrap();
+    assert_eq!(parsed.as_bytes().len(), 67);
+    assert_eq!(parsed.to_bitcoind_asm().unwrap(), output);
+
+    let s = Script::from_bitcoind_asm("2 OP_DROP -5 0 -1 16 17 1000").unwrap();
+    assert_eq!(s, Script::new().push_int(2).push_opcode(OP_DROP).push_int(-5).push_int(0).push_int(-1).push_int(16).push_int(17).push_int(1000));
+    assert_eq!(s.to_bitcoind_asm().unwrap(), "2 OP_DROP -5 0 -1 16 17 1000");
+    // the ts-sdk dialect reads the same tokens as hex
+    assert_eq!(Script::from_asm("2").unwrap().into_bytes(), vec![0x01, 0x02]);
+}
+
+#[test]
+fn rejects_bad_tokens() {
+    assert!(Script::from_asm("OP_NOTREAL").is_err());
+    assert!(Script::from_asm("zz").is_err());
+    assert!(Script(vec![0x05, 0x01]).to_asm().is_err());
+}
+
+#[test]
+fn chunk_display() {
+    assert_eq!(Chunk::Op(Opcode::OP_CHECKSIG).to_string(), "OP_CHECKSIG");
+    assert_eq!(Chunk::Op(Opcode::from(0xba)).to_string(), "OP_NOP11");
+    // ts-sdk template placeholders parse by name
+    assert_eq!(Script::from_asm("OP_SMALLINTEGER OP_PUBKEY OP_INVALIDOPCODE").unwrap().into_bytes(), vec![0xfa, 0xfe, 0xff]);
+    assert_eq!(Chunk::Op(Opcode::OP_FALSE).to_string(), "0");
+    assert_eq!(Chunk::Push(vec![0xde, 0xad]).to_string(), "dead");
+    let chunks = Script::from_asm("OP_2 OP_ADD").unwrap().parse().unwrap();
+    assert_eq!(chunks, vec![Chunk::Op(Opcode::OP_2), Chunk::Op(Opcode::OP_ADD)]);
+}
@@ -0,0 +1,37 @@
+use rs_sdk::script::{Script, ScriptNum, OP_0, OP_1, OP_16, OP_1NEGATE, OP_PUSHDATA1};
+
+#[test]
+fn push_int_uses_small_int_opcodes_and_script_numbers() {
+    assert_eq!(Script::new().push_int(0).into_bytes(), vec![OP_0]);
+    assert_eq!(Script::new().push_int(-1).into_bytes(), vec![OP_1NEGATE]);
+    assert_eq!(Script::new().push_int(16).into_bytes(), vec![OP_16]);
+    assert_eq!(Script::new().push_int(17).into_bytes(), vec![0x01, 0x11]);
+    assert_eq!(Script::new().push_int(-2).into_bytes(), vec![0x01, 0x82]);
+    assert_eq!(Script::new().push_int(1000).into_bytes(), vec![0x02, 0xe8, 0x03]);
+    // 200 needs a sign byte; the raw byte would read back as -72
+    assert_eq!(Script::new().push_small_int(200).into_bytes(), vec![0x02, 0xc8, 0x00]);
+    assert_eq!(ScriptNum::decode_minimal(&[0xc8, 0x00]).unwrap(), ScriptNum(200));
+}
+
+#[test]
+fn push_data_minimal_is_opt_in() {
+    assert_eq!(Script::new().push_data_minimal(&[]).into_bytes(), vec![OP_0]);
+    assert_eq!(Script::new().push_data_minimal(&[1]).into_bytes(), vec![OP_1]);
+    assert_eq!(Script::new().push_data_minimal(&[0x81]).into_bytes(), vec![OP_1NEGATE]);
+    assert_eq!(Script::new().push_data_minimal(&[0]).into_bytes(), vec![0x01, 0x00]);
+    assert_eq!(Script::new().push_data_minimal(&[17]).into_bytes(), vec![0x01, 17]);
+    assert_eq!(Script::new().push_data(&[1]).into_bytes(), vec![0x01, 0x01]);
+    assert_eq!(Script::new().push_data(&[0x81]).into_bytes(), vec![0x01, 0x81]);
+    let long = Script::new().push_data_minimal(&[7; 76]).into_bytes();
+    assert_eq!(&long[..2], &[OP_PUSHDATA1, 76]);
+}
+
+#[test]
+fn minimal_encoding_check() {
+    let built = Script::new().push_int(5).push_data_minimal(&[9; 80]).push_int(-300).push_data_minimal(b"");
+    assert!(built.is_minimally_encoded());
+    assert!(!Script::new().push_data(&[5]).is_minimally_encoded());
+    assert!(!Script(vec![OP_PUSHDATA1, 1, 0xaa]).is_minimally_encoded());
+    assert!(!Script(vec![OP_PUSHDATA1, 0]).is_minimally_encoded());
+    assert!(!Script(vec![0x05, 0x01]).is_minimally_encoded());
+}
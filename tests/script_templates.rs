@@ -58,7 +58,7 @@ fn r_puzzle_locking_script_placeholder_encoding() {
 }
 
 // Parser roundtrip checks
-use rs_sdk::script::{Script, Chunk};
+use rs_sdk::script::Chunk;
 
 #[test]
 fn parse_p2pkh_locking_chunks() {
@@ -82,3 +82,15 @@ fn parse_push_drop_chunks() {
     assert!(matches!(chunks[1], Chunk::Op(op) if op == OP_DROP));
     assert_eq!(chunks.len(), 2);
 }
+
+#[test]
+fn p2pkh_signed_unlocking_script_appends_sighash_flag() {
+    use rs_sdk::crypto::{sign_hash, PrivateKey};
+    let key = PrivateKey::from_bytes(&[0x42u8; 32]).unwrap();
+    let sig = sign_hash(&key, &[0x01u8; 32]).unwrap();
+    let pubkey = key.to_public_key();
+    let chunks = P2pkhTemplate::unlocking_script_signed(&sig, 0x41, &pubkey).parse().expect("parse ok");
+    let mut expected_sig = sig.to_der();
+    expected_sig.push(0x41);
+    assert_eq!(chunks, vec![Chunk::Push(expected_sig), Chunk::Push(pubkey.as_bytes().to_vec())]);
+}
@@ -0,0 +1,41 @@
+use rs_sdk::address::Network;
+use rs_sdk::crypto::{sha256, PrivateKey, Sha256};
+use rs_sdk::script::templates::{P2pkhTemplate, PushDropTemplate, RPuzzleTemplate};
+use rs_sdk::script::{ScriptTemplate, Spend, UnlockingScriptTemplate};
+use rs_sdk::transaction::{serialize, OutPoint, TxBuilder, TxOut};
+
+fn prevout(n: u8) -> OutPoint { OutPoint { txid: [n; 32], vout: n as u32 } }
+
+#[test]
+fn builder_signs_mixed_inputs() {
+    let key = PrivateKey::from_bytes(&[0x31; 32]).unwrap();
+    let pubkey_hash = rs_sdk::address::from_pubkey(&key.to_public_key(), Network::Mainnet).payload;
+    let Sha256(digest) = sha256(&[0x5e; 32]);
+    let sources = [
+        TxOut { value: 5_000, script_pubkey: P2pkhTemplate.lock(pubkey_hash).into_bytes() },
+        TxOut { value: 1_000, script_pubkey: PushDropTemplate.lock(b"token".to_vec()).into_bytes() },
+        TxOut { value: 2_000, script_pubkey: RPuzzleTemplate.lock(digest).into_bytes() },
+    ];
+    let builder = TxBuilder::new()
+        .input_unlocking(prevout(1), P2pkhTemplate.unlock((key, sources[0].clone())))
+        .input_unlocking(prevout(2), PushDropTemplate.unlock(()))
+        .input_unlocking(prevout(3), RPuzzleTemplate.unlock(vec![0x5e; 32]))
+        .p2pkh_output(7_500, pubkey_hash);
+    let estimate = builder.estimated_size();
+    let tx = builder.build_signed().unwrap();
+    let size = serialize(&tx).len();
+    assert!(size <= estimate && estimate - size <= 2, "estimate {} vs actual {}", estimate, size);
+    for (i, source) in sources.iter().enumerate() {
+        let locking = rs_sdk::script::Script(source.script_pubkey.clone());
+        Spend::new(&tx, i, &locking, source.value).validate().unwrap();
+    }
+}
+
+#[test]
+fn unsigned_build_and_estimates() {
+    let unlocker = RPuzzleTemplate.unlock(vec![7; 100]);
+    let tx = TxBuilder::new().input_unlocking(prevout(1), unlocker.clone()).build();
+    assert!(tx.vin[0].script_sig.is_empty());
+    assert_eq!(unlocker.estimate_length(&tx, 0), 102);
+    assert_eq!(unlocker.sign(&tx, 0).unwrap().into_bytes().len(), 102);
+}
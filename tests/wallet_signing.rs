@@ -0,0 +1,23 @@
+#![cfg(feature = "std")]
+use rs_sdk::crypto::{KeyPair, PrivateKey};
+use rs_sdk::script::templates::P2pkhTemplate;
+use rs_sdk::script::{ScriptTemplate, Spend};
+use rs_sdk::storage::Storage;
+use rs_sdk::transaction::{OutPoint, TxBuilder, TxOut};
+use rs_sdk::wallet::{Wallet, WalletConfig};
+
+struct NoStorage;
+impl Storage for NoStorage {}
+
+#[test]
+fn wallet_provides_p2pkh_unlocker() {
+    let mut wallet = Wallet::new(WalletConfig::default(), NoStorage);
+    let source = TxOut { value: 9_000, script_pubkey: Vec::new() };
+    assert!(wallet.p2pkh_unlocker(source.clone()).is_none());
+    wallet.set_keypair(KeyPair::from_private(PrivateKey::from_bytes(&[0x44; 32]).unwrap()));
+    let source = TxOut { script_pubkey: wallet.address().unwrap().to_locking_script().into_bytes(), ..source };
+    let unlocker = wallet.p2pkh_unlocker(source.clone()).unwrap();
+    let tx = TxBuilder::new().input_unlocking(OutPoint { txid: [4; 32], vout: 4 }, unlocker).output(8_000, P2pkhTemplate.lock([0; 20])).build_signed().unwrap();
+    let locking = rs_sdk::script::Script(source.script_pubkey);
+    assert!(Spend::new(&tx, 0, &locking, source.value).validate().is_ok());
+}